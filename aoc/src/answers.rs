@@ -0,0 +1,196 @@
+use crate::cli::{default_input_file, InputSource, Task};
+use common::{Answer, LineParser, ParseError};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// The result of comparing an answer with the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    // The expected answer.
+    Fail(String),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail(_) => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Fail(expected) => write!(f, "FAIL, expected {}", expected),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/// The known answers of the tasks of one day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    first: Option<String>,
+    second: Option<String>,
+}
+
+impl KnownAnswers {
+    /// Function that compares the answer with the known answer of the task.
+    pub fn verify(&self, task: Task, answer: &Answer) -> Verdict {
+        let known_answer = match task {
+            Task::First => &self.first,
+            Task::Second => &self.second,
+        };
+        match known_answer {
+            Some(expected) if *expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// Function that parses an answers file. It is a small subset of TOML: sections like
+/// `[day-07]`, answers like `first = 251806792` (the value may be quoted) and comments
+/// that start with '#'. Answers in front of the first section belong to the section "".
+fn parse_answers(content: &str) -> Result<HashMap<String, KnownAnswers>, ParseError> {
+    let mut sections: HashMap<String, KnownAnswers> = HashMap::new();
+    let mut section = String::new();
+    for (idx, line) in content.lines().enumerate() {
+        let mut parser = LineParser::new(line).with_line_number(idx + 1);
+        parser.skip_whitespace();
+        if parser.is_at_end() || parser.peek() == Some('#') {
+            continue;
+        }
+        if parser.accept("[") {
+            section = parser
+                .take_while("a section name", |c| c.is_ascii_alphanumeric() || c == '-')?
+                .to_string();
+            parser.expect("]")?;
+        } else {
+            let key_parser = parser.clone();
+            let key = parser.take_while("'first' or 'second'", |c| c.is_ascii_alphanumeric())?;
+            parser.skip_whitespace();
+            parser.expect("=")?;
+            parser.skip_whitespace();
+            let value = if parser.accept("\"") {
+                let value = parser.take_while("an answer", |c| c != '"')?;
+                parser.expect("\"")?;
+                value
+            } else {
+                parser.take_while("an answer", |c| !c.is_whitespace() && c != '#')?
+            };
+            let known_answers = sections.entry(section.clone()).or_default();
+            match key {
+                "first" => known_answers.first = Some(value.to_string()),
+                "second" => known_answers.second = Some(value.to_string()),
+                _ => {
+                    return Err(key_parser
+                        .error("'first' or 'second'")
+                        .found(format!("'{}'", key)))
+                }
+            }
+        }
+        parser.skip_whitespace();
+        if parser.peek() != Some('#') {
+            parser.end()?;
+        }
+    }
+    Ok(sections)
+}
+
+/// Function that reads the answers of the given section of an answers file.
+fn read_answers(path: &Path, section: &str) -> Result<KnownAnswers, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+    let mut sections = parse_answers(&content)
+        .map_err(|error| error.with_file(path.display().to_string()).to_string())?;
+    Ok(sections.remove(section).unwrap_or_default())
+}
+
+/// Function that returns the answers file that belongs to the input file, which is
+/// next to it and named like it ('input' -> 'answers', 'input.test' -> 'answers.test').
+fn answers_file_next_to(input_file: &str) -> Option<PathBuf> {
+    let path = Path::new(input_file);
+    let suffix = path.file_name()?.to_str()?.strip_prefix("input")?;
+    Some(path.with_file_name(format!("answers{}", suffix)))
+}
+
+fn is_same_file(a: &str, b: &str) -> bool {
+    match (fs::canonicalize(a), fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Function that loads the known answers for the input of a day. The answers file
+/// next to the input file is preferred. The central answers file only knows the
+/// answers of the puzzle inputs, so it is only used for them.
+pub fn load_known_answers(
+    day: usize,
+    input: &InputSource,
+    answers_file: &str,
+) -> Result<KnownAnswers, String> {
+    let input_file = match input {
+        InputSource::File(input_file) => input_file,
+        InputSource::Stdin => return Ok(KnownAnswers::default()),
+    };
+    if let Some(path) = answers_file_next_to(input_file) {
+        if path.exists() {
+            return read_answers(&path, "");
+        }
+    }
+    if is_same_file(input_file, &default_input_file(day)) && Path::new(answers_file).exists() {
+        return read_answers(Path::new(answers_file), &format!("day-{:02}", day));
+    }
+    Ok(KnownAnswers::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let sections = parse_answers(
+            "first = 1\n\n# Comment.\n[day-07]\nfirst = 251806792\nsecond = \"abc\" # Text.\n",
+        )
+        .unwrap();
+        let known_answers = &sections["day-07"];
+        assert_eq!(
+            known_answers.verify(Task::First, &Answer::Unsigned(251806792)),
+            Verdict::Pass
+        );
+        assert_eq!(
+            known_answers.verify(Task::Second, &Answer::Unsigned(5)),
+            Verdict::Fail(String::from("abc"))
+        );
+        assert_eq!(
+            sections[""].verify(Task::Second, &Answer::Unsigned(5)),
+            Verdict::Unknown
+        );
+        assert_eq!(
+            parse_answers("[day-07]\nthird = 3").unwrap_err(),
+            ParseError::new(2, 1, "'first' or 'second'").found("'third'")
+        );
+    }
+
+    #[test]
+    fn test_answers_file_next_to() {
+        assert_eq!(
+            answers_file_next_to("./day-07/input"),
+            Some(PathBuf::from("./day-07/answers"))
+        );
+        assert_eq!(
+            answers_file_next_to("./day-10/input1_2.test"),
+            Some(PathBuf::from("./day-10/answers1_2.test"))
+        );
+        assert_eq!(answers_file_next_to("./my_puzzle.txt"), None);
+    }
+}
@@ -0,0 +1,374 @@
+use crate::cli::Task;
+use common::{LineParser, ParseError, Solution};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    hint::black_box,
+    io::Cursor,
+    time::{Duration, Instant},
+};
+
+/// How often and how long the parts of a day are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchSettings {
+    pub warmup: usize,
+    pub iterations: usize,
+    // Warming up and measuring a part stops once this time is over (but every part is
+    // measured at least once), so that the slow days do not take forever.
+    pub max_time: Duration,
+}
+
+impl Default for BenchSettings {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 10,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// The part of a day that is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Solve(Task),
+}
+
+impl Stage {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "first" => Some(Stage::Solve(Task::First)),
+            "second" => Some(Stage::Solve(Task::Second)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(Task::First) => write!(f, "first"),
+            Stage::Solve(Task::Second) => write!(f, "second"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Statistics {
+    pub iterations: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    /// Function that calculates the statistics of the measured times (at least one).
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        // Sample standard deviation (zero for a single sample).
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            iterations: n,
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: usize,
+    pub stage: Stage,
+    pub statistics: Statistics,
+}
+
+/// Function that runs the function a few times for warmup and measures it afterwards.
+fn measure<F: FnMut()>(settings: &BenchSettings, mut function: F) -> Statistics {
+    let start = Instant::now();
+    for _ in 0..settings.warmup {
+        if start.elapsed() >= settings.max_time {
+            break;
+        }
+        function();
+    }
+    let start = Instant::now();
+    let mut samples: Vec<Duration> = Vec::with_capacity(settings.iterations);
+    while samples.is_empty()
+        || (samples.len() < settings.iterations && start.elapsed() < settings.max_time)
+    {
+        let sample_start = Instant::now();
+        function();
+        samples.push(sample_start.elapsed());
+    }
+    Statistics::from_samples(&samples)
+}
+
+/// Function that measures parsing and solving both tasks of a day. Every task is
+/// solved with the same parsed input, so that only the algorithm is measured.
+pub fn bench_solution<S: Solution>(
+    day: usize,
+    input: &[u8],
+    settings: &BenchSettings,
+) -> Result<Vec<Measurement>, ParseError> {
+    let parsed_input = S::parse(Cursor::new(input))?;
+    let parameters = S::Parameters::default();
+    let parse = measure(settings, || {
+        black_box(S::parse(Cursor::new(black_box(input))).ok());
+    });
+    let first = measure(settings, || {
+        black_box(S::solve_first_task(black_box(&parsed_input), &parameters));
+    });
+    let second = measure(settings, || {
+        black_box(S::solve_second_task(black_box(&parsed_input), &parameters));
+    });
+    Ok([
+        (Stage::Parse, parse),
+        (Stage::Solve(Task::First), first),
+        (Stage::Solve(Task::Second), second),
+    ]
+    .into_iter()
+    .map(|(stage, statistics)| Measurement {
+        day,
+        stage,
+        statistics,
+    })
+    .collect())
+}
+
+/// The median times of an earlier benchmark.
+pub type Baseline = HashMap<(usize, Stage), Duration>;
+
+/// Function that saves the median times. Every line is '<day> <part> <median in ns>'.
+pub fn save_baseline(path: &str, measurements: &[Measurement]) -> Result<(), String> {
+    let mut content = String::from("# day part median_ns\n");
+    for measurement in measurements.iter() {
+        content.push_str(&format!(
+            "{} {} {}\n",
+            measurement.day,
+            measurement.stage,
+            measurement.statistics.median.as_nanos()
+        ));
+    }
+    fs::write(path, content).map_err(|error| format!("cannot write {}: {}", path, error))
+}
+
+fn parse_baseline(content: &str) -> Result<Baseline, ParseError> {
+    let mut baseline = Baseline::new();
+    for (idx, line) in content.lines().enumerate() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let mut parser = LineParser::new(line).with_line_number(idx + 1);
+        let day = parser.number::<usize>()?;
+        parser.skip_whitespace();
+        let stage_parser = parser.clone();
+        let stage_name = parser.word()?;
+        let stage = match Stage::from_name(stage_name) {
+            Some(stage) => stage,
+            None => {
+                return Err(stage_parser
+                    .error("'parse', 'first' or 'second'")
+                    .found(format!("'{}'", stage_name)))
+            }
+        };
+        let median = parser.number::<u64>()?;
+        parser.end()?;
+        baseline.insert((day, stage), Duration::from_nanos(median));
+    }
+    Ok(baseline)
+}
+
+pub fn load_baseline(path: &str) -> Result<Baseline, String> {
+    let content =
+        fs::read_to_string(path).map_err(|error| format!("cannot read {}: {}", path, error))?;
+    parse_baseline(&content).map_err(|error| error.with_file(path).to_string())
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.3} s", nanos as f64 / 1e9)
+    }
+}
+
+fn format_change(median: Duration, baseline: Duration) -> String {
+    if baseline.is_zero() {
+        return String::from("-");
+    }
+    let change = (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", change)
+}
+
+/// Function that creates the summary table of all measurements. With a baseline, the
+/// table also shows the median of the baseline and the change compared to it.
+pub fn format_table(measurements: &[Measurement], baseline: Option<&Baseline>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![["Day", "Part", "Median", "Min", "Stddev", "Runs"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()];
+    if baseline.is_some() {
+        rows[0].push(String::from("Baseline"));
+        rows[0].push(String::from("Change"));
+    }
+    let mut total = Duration::ZERO;
+    // Only the parts that are part of the baseline are summed up for the change.
+    let mut total_compared = Duration::ZERO;
+    let mut total_baseline = Duration::ZERO;
+    for measurement in measurements.iter() {
+        let statistics = &measurement.statistics;
+        total += statistics.median;
+        let mut row = vec![
+            format!("{:02}", measurement.day),
+            measurement.stage.to_string(),
+            format_duration(statistics.median),
+            format_duration(statistics.min),
+            format_duration(statistics.stddev),
+            statistics.iterations.to_string(),
+        ];
+        if let Some(baseline) = baseline {
+            match baseline.get(&(measurement.day, measurement.stage)) {
+                Some(&baseline_median) => {
+                    total_compared += statistics.median;
+                    total_baseline += baseline_median;
+                    row.push(format_duration(baseline_median));
+                    row.push(format_change(statistics.median, baseline_median));
+                }
+                None => {
+                    row.push(String::from("-"));
+                    row.push(String::from("-"));
+                }
+            }
+        }
+        rows.push(row);
+    }
+    let mut total_row = vec![
+        String::from("Total"),
+        String::new(),
+        format_duration(total),
+        String::new(),
+        String::new(),
+        String::new(),
+    ];
+    if baseline.is_some() {
+        total_row.push(format_duration(total_baseline));
+        total_row.push(format_change(total_compared, total_baseline));
+    }
+    rows.push(total_row);
+    // Align all columns (the text columns to the left, the times to the right).
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect();
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(col, cell)| {
+                    if col < 2 {
+                        format!("{:<width$}", cell, width = widths[col])
+                    } else {
+                        format!("{:>width$}", cell, width = widths[col])
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statistics() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect();
+        let statistics = Statistics::from_samples(&samples);
+        assert_eq!(statistics.iterations, 4);
+        assert_eq!(statistics.median, Duration::from_micros(2500));
+        assert_eq!(statistics.min, Duration::from_millis(1));
+        // The sample standard deviation of 1, 2, 3 and 4 is sqrt(5/3).
+        assert_eq!(statistics.stddev.as_micros(), 1290);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let baseline = parse_baseline("# day part median_ns\n7 parse 1500\n7 second 20\n").unwrap();
+        assert_eq!(baseline[&(7, Stage::Parse)], Duration::from_nanos(1500));
+        assert_eq!(
+            baseline[&(7, Stage::Solve(Task::Second))],
+            Duration::from_nanos(20)
+        );
+        assert_eq!(
+            parse_baseline("7 third 20").unwrap_err(),
+            ParseError::new(1, 3, "'parse', 'first' or 'second'").found("'third'")
+        );
+    }
+
+    #[test]
+    fn test_format_table() {
+        let measurement = |stage, median| Measurement {
+            day: 7,
+            stage,
+            statistics: Statistics {
+                iterations: 10,
+                median,
+                min: median,
+                stddev: Duration::from_nanos(5),
+            },
+        };
+        let measurements = vec![
+            measurement(Stage::Parse, Duration::from_micros(200)),
+            measurement(Stage::Solve(Task::First), Duration::from_millis(2)),
+        ];
+        let baseline: Baseline = [((7, Stage::Parse), Duration::from_micros(250))]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            format_table(&measurements, Some(&baseline)),
+            [
+                "Day    Part     Median       Min  Stddev  Runs  Baseline  Change",
+                "07     parse  200.0 µs  200.0 µs    5 ns    10  250.0 µs  -20.0%",
+                "07     first   2.00 ms   2.00 ms    5 ns    10         -       -",
+                "Total          2.20 ms                          250.0 µs  -20.0%",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use crate::bench::BenchSettings;
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const USAGE: &str =
+    "Usage: aoc run <day> [first|second|1|2|both] [--input <file>|<file>] [--format text|json]
+               [--verify] [--answers <file>] [--seed <n>|random] [--verbose]
+       aoc verify [<day>] [--answers <file>]
+       aoc bench [<day>] [--warmup <n>] [--iterations <n>] [--max-time <seconds>]
+                 [--save-baseline <file>] [--baseline <file>]
+       aoc --help
+
+Run (solve the tasks of a day):
+  <day>           The day to solve (1 to 25).
+  [task]          The task to solve: first (1), second (2) or both (default: first).
+                  With both, the input is parsed once for both tasks.
+  -i, --input <file>
+                  The input file (default: ./day-<day>/input). Use - to read from stdin.
+  -f, --format <text|json>
+                  The output format (default: text). The JSON output contains the
+                  day, part, answer, parse time and solve time of every task.
+  --verify        Compare the answers with the known answers (PASS, FAIL or UNKNOWN).
+                  They are read from the file 'answers' next to the input file (for
+                  the input file 'input.test' it is 'answers.test') or, for the puzzle
+                  input, from the section [day-<day>] of the answers file.
+  --answers <file>
+                  The answers file (default: ./answers.toml).
+  --seed <n|random>
+                  The seed for solutions that use random numbers (day 25 then guesses
+                  the wires to cut from random samples). With random, a new seed is
+                  chosen. Days without random numbers reject it.
+  -v, --verbose   Print the seed to stderr, so that the run can be repeated.
+
+Verify (compare the answers of the puzzle inputs with the known answers):
+  [<day>]         The day to verify (default: all days). Both tasks are solved with
+                  the puzzle input.
+  --answers <file>
+                  The answers file (default: ./answers.toml).
+
+Bench (measure parsing and both tasks with the puzzle input):
+  [<day>]         The day to benchmark (default: all days).
+  --warmup <n>    The number of runs before measuring (default: 3).
+  --iterations <n>
+                  The number of measured runs (default: 10).
+  --max-time <seconds>
+                  Stop warming up and measuring a part once this time is over, but
+                  measure at least once (default: 5).
+  --save-baseline <file>
+                  Save the median times as a baseline.
+  --baseline <file>
+                  Compare the median times with a saved baseline.
+
+  -h, --help      Print this help.";
+
+pub const DEFAULT_ANSWERS_FILE: &str = "./answers.toml";
+
+/// Function that returns the path of the puzzle input of the given day.
+pub fn default_input_file(day: usize) -> String {
+    format!("./day-{:02}/input", day)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Task {
+    First,
+    Second,
+}
+
+impl Task {
+    pub fn number(&self) -> usize {
+        match self {
+            Task::First => 1,
+            Task::Second => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(String),
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(filename) => write!(f, "{}", filename),
+        }
+    }
+}
+
+/// The seed for the random numbers of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seed {
+    Fixed(u64),
+    /// A new seed is chosen for every run.
+    Random,
+}
+
+impl Seed {
+    /// Function that returns the seed, choosing one from the current time if it is
+    /// random.
+    pub fn choose(&self) -> u64 {
+        match self {
+            Seed::Fixed(seed) => *seed,
+            Seed::Random => SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArguments {
+    pub day: usize,
+    pub tasks: Vec<Task>,
+    pub input: InputSource,
+    pub format: OutputFormat,
+    pub verify: bool,
+    pub answers: String,
+    pub seed: Option<Seed>,
+    pub verbose: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArguments {
+    pub days: Vec<usize>,
+    pub answers: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BenchArguments {
+    pub days: Vec<usize>,
+    pub settings: BenchSettings,
+    pub save_baseline: Option<String>,
+    pub baseline: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    Run(RunArguments),
+    Verify(VerifyArguments),
+    Bench(BenchArguments),
+}
+
+/// The options that can be given anywhere after the command.
+#[derive(Debug, Default)]
+struct Options {
+    help: bool,
+    // The long names of all given options, to check if the command supports them.
+    given: Vec<String>,
+    input: Option<String>,
+    format: OutputFormat,
+    verify: bool,
+    answers: Option<String>,
+    warmup: Option<usize>,
+    iterations: Option<usize>,
+    max_time: Option<Duration>,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+    seed: Option<Seed>,
+    verbose: bool,
+}
+
+impl Options {
+    /// Function that returns an error if an option was given that the command does
+    /// not support.
+    fn check_supported(&self, command: &str, supported: &[&str]) -> Result<(), String> {
+        match self
+            .given
+            .iter()
+            .find(|name| !supported.contains(&name.as_str()))
+        {
+            Some(name) => Err(format!("option '{}' is not supported by {}", name, command)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Function that parses the task name. The tasks can also be given by their number.
+fn parse_tasks(arg: &str) -> Result<Vec<Task>, String> {
+    match arg {
+        "first" | "1" => Ok(vec![Task::First]),
+        "second" | "2" => Ok(vec![Task::Second]),
+        "both" => Ok(vec![Task::First, Task::Second]),
+        _ => Err(format!(
+            "unknown task '{}' (expected first, second, 1, 2 or both)",
+            arg
+        )),
+    }
+}
+
+fn parse_format(arg: &str) -> Result<OutputFormat, String> {
+    match arg {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(format!("unknown format '{}' (expected text or json)", arg)),
+    }
+}
+
+fn parse_seed(arg: &str) -> Result<Seed, String> {
+    match arg {
+        "random" => Ok(Seed::Random),
+        _ => arg.parse::<u64>().map(Seed::Fixed).map_err(|_| {
+            format!(
+                "invalid value '{}' for '--seed' (expected a number or random)",
+                arg
+            )
+        }),
+    }
+}
+
+fn parse_day(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day '{}' (expected 1 to 25)", arg)),
+    }
+}
+
+fn parse_count(name: &str, arg: &str) -> Result<usize, String> {
+    arg.parse::<usize>()
+        .map_err(|_| format!("invalid value '{}' for '{}' (expected a number)", arg, name))
+}
+
+fn parse_seconds(name: &str, arg: &str) -> Result<Duration, String> {
+    match arg.parse::<f64>() {
+        Ok(seconds) if seconds >= 0.0 && seconds.is_finite() => {
+            Ok(Duration::from_secs_f64(seconds))
+        }
+        _ => Err(format!(
+            "invalid value '{}' for '{}' (expected a number of seconds)",
+            arg, name
+        )),
+    }
+}
+
+/// Function that separates the options from the positional arguments. Options with
+/// a value can be given as '--name value' or '--name=value'.
+fn parse_options<I: Iterator<Item = String>>(
+    mut args: I,
+) -> Result<(Vec<String>, Options), String> {
+    let mut positionals: Vec<String> = Vec::new();
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            options.help = true;
+            continue;
+        }
+        if !arg.starts_with('-') || arg == "-" {
+            positionals.push(arg);
+            continue;
+        }
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let name = match name {
+            "-i" => "--input",
+            "-f" => "--format",
+            "-v" => "--verbose",
+            _ => name,
+        };
+        // Flags do not take a value, so '--verify=false' must not turn verification on.
+        let flag = || match &inline_value {
+            Some(_) => Err(format!("option '{}' does not take a value", name)),
+            None => Ok(true),
+        };
+        let mut value = || match inline_value.clone().or_else(|| args.next()) {
+            Some(value) => Ok(value),
+            None => Err(format!("missing value after '{}'", name)),
+        };
+        match name {
+            "--input" => options.input = Some(value()?),
+            "--format" => options.format = parse_format(&value()?)?,
+            "--verify" => options.verify = flag()?,
+            "--answers" => options.answers = Some(value()?),
+            "--warmup" => options.warmup = Some(parse_count(name, &value()?)?),
+            "--iterations" => options.iterations = Some(parse_count(name, &value()?)?),
+            "--max-time" => options.max_time = Some(parse_seconds(name, &value()?)?),
+            "--save-baseline" => options.save_baseline = Some(value()?),
+            "--baseline" => options.baseline = Some(value()?),
+            "--seed" => options.seed = Some(parse_seed(&value()?)?),
+            "--verbose" => options.verbose = flag()?,
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+        options.given.push(name.to_string());
+    }
+    Ok((positionals, options))
+}
+
+/// Function that parses the days to verify or benchmark (default: all days).
+fn parse_days<I: Iterator<Item = String>>(mut positionals: I) -> Result<Vec<usize>, String> {
+    let days = match positionals.next() {
+        Some(day) => vec![parse_day(&day)?],
+        None => (1..=25).collect(),
+    };
+    match positionals.next() {
+        Some(arg) => Err(format!("unexpected argument '{}'", arg)),
+        None => Ok(days),
+    }
+}
+
+/// Function that parses the command line arguments (without the program name).
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err(String::from("missing command")),
+    };
+    if command == "-h" || command == "--help" {
+        return Ok(Command::Help);
+    }
+    if !["run", "verify", "bench"].contains(&command.as_str()) {
+        return Err(format!("unknown command '{}'", command));
+    }
+    let (positionals, options) = parse_options(args)?;
+    if options.help {
+        return Ok(Command::Help);
+    }
+    let mut positionals = positionals.into_iter();
+    if command == "bench" {
+        options.check_supported(
+            &command,
+            &[
+                "--warmup",
+                "--iterations",
+                "--max-time",
+                "--save-baseline",
+                "--baseline",
+            ],
+        )?;
+        let default_settings = BenchSettings::default();
+        return Ok(Command::Bench(BenchArguments {
+            days: parse_days(positionals)?,
+            settings: BenchSettings {
+                warmup: options.warmup.unwrap_or(default_settings.warmup),
+                iterations: options
+                    .iterations
+                    .unwrap_or(default_settings.iterations)
+                    .max(1),
+                max_time: options.max_time.unwrap_or(default_settings.max_time),
+            },
+            save_baseline: options.save_baseline,
+            baseline: options.baseline,
+        }));
+    }
+    let answers = options
+        .answers
+        .clone()
+        .unwrap_or_else(|| String::from(DEFAULT_ANSWERS_FILE));
+    if command == "verify" {
+        // All days are verified with their puzzle input, so only the day can be given.
+        options.check_supported(&command, &["--answers"])?;
+        return Ok(Command::Verify(VerifyArguments {
+            days: parse_days(positionals)?,
+            answers,
+        }));
+    }
+    options.check_supported(
+        &command,
+        &[
+            "--input",
+            "--format",
+            "--verify",
+            "--answers",
+            "--seed",
+            "--verbose",
+        ],
+    )?;
+    let day = match positionals.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err(String::from("missing day")),
+    };
+    let tasks = match positionals.next() {
+        Some(task) => parse_tasks(&task)?,
+        None => vec![Task::First],
+    };
+    // The input file can also be given as the last positional argument.
+    let mut input = options.input;
+    if let Some(filename) = positionals.next() {
+        if input.is_some() {
+            return Err(String::from("the input file is given twice"));
+        }
+        input = Some(filename);
+    }
+    if let Some(arg) = positionals.next() {
+        return Err(format!("unexpected argument '{}'", arg));
+    }
+    let input = match input {
+        Some(filename) if filename == "-" => InputSource::Stdin,
+        Some(filename) => InputSource::File(filename),
+        None => InputSource::File(default_input_file(day)),
+    };
+    Ok(Command::Run(RunArguments {
+        day,
+        tasks,
+        input,
+        format: options.format,
+        verify: options.verify,
+        answers,
+        seed: options.seed,
+        verbose: options.verbose,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_ascii_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse("run 7"),
+            Ok(Command::Run(RunArguments {
+                day: 7,
+                tasks: vec![Task::First],
+                input: InputSource::File(String::from("./day-07/input")),
+                format: OutputFormat::Text,
+                verify: false,
+                answers: String::from("./answers.toml"),
+                seed: None,
+                verbose: false,
+            }))
+        );
+        assert_eq!(
+            parse("run 7 2 my_input"),
+            Ok(Command::Run(RunArguments {
+                day: 7,
+                tasks: vec![Task::Second],
+                input: InputSource::File(String::from("my_input")),
+                format: OutputFormat::Text,
+                verify: false,
+                answers: String::from("./answers.toml"),
+                seed: None,
+                verbose: false,
+            }))
+        );
+        assert_eq!(
+            parse("run --input - 19 both --format json"),
+            Ok(Command::Run(RunArguments {
+                day: 19,
+                tasks: vec![Task::First, Task::Second],
+                input: InputSource::Stdin,
+                format: OutputFormat::Json,
+                verify: false,
+                answers: String::from("./answers.toml"),
+                seed: None,
+                verbose: false,
+            }))
+        );
+        assert_eq!(
+            parse("run 12 both --verify --answers my_answers.toml"),
+            Ok(Command::Run(RunArguments {
+                day: 12,
+                tasks: vec![Task::First, Task::Second],
+                input: InputSource::File(String::from("./day-12/input")),
+                format: OutputFormat::Text,
+                verify: true,
+                answers: String::from("my_answers.toml"),
+                seed: None,
+                verbose: false,
+            }))
+        );
+        assert_eq!(
+            parse("run 25 --seed 7 -v"),
+            Ok(Command::Run(RunArguments {
+                day: 25,
+                tasks: vec![Task::First],
+                input: InputSource::File(String::from("./day-25/input")),
+                format: OutputFormat::Text,
+                verify: false,
+                answers: String::from("./answers.toml"),
+                seed: Some(Seed::Fixed(7)),
+                verbose: true,
+            }))
+        );
+        assert!(matches!(
+            parse("run 25 --seed=random"),
+            Ok(Command::Run(RunArguments {
+                seed: Some(Seed::Random),
+                ..
+            }))
+        ));
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify(VerifyArguments {
+                days: (1..=25).collect(),
+                answers: String::from("./answers.toml"),
+            }))
+        );
+        assert_eq!(
+            parse("verify 23"),
+            Ok(Command::Verify(VerifyArguments {
+                days: vec![23],
+                answers: String::from("./answers.toml"),
+            }))
+        );
+        assert_eq!(
+            parse("bench 21 --iterations=3 --max-time 0.5 --baseline old"),
+            Ok(Command::Bench(BenchArguments {
+                days: vec![21],
+                settings: BenchSettings {
+                    warmup: 3,
+                    iterations: 3,
+                    max_time: Duration::from_millis(500),
+                },
+                save_baseline: None,
+                baseline: Some(String::from("old")),
+            }))
+        );
+        assert_eq!(parse("run 7 first --help"), Ok(Command::Help));
+        assert_eq!(parse("--help"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert_eq!(parse(""), Err(String::from("missing command")));
+        assert_eq!(parse("run"), Err(String::from("missing day")));
+        assert_eq!(
+            parse("run 26"),
+            Err(String::from("invalid day '26' (expected 1 to 25)"))
+        );
+        assert_eq!(
+            parse("run 7 third"),
+            Err(String::from(
+                "unknown task 'third' (expected first, second, 1, 2 or both)"
+            ))
+        );
+        assert_eq!(
+            parse("run 7 first --quiet"),
+            Err(String::from("unknown option '--quiet'"))
+        );
+        assert_eq!(
+            parse("run 7 first --format xml"),
+            Err(String::from("unknown format 'xml' (expected text or json)"))
+        );
+        assert_eq!(
+            parse("run 7 first input --input other"),
+            Err(String::from("the input file is given twice"))
+        );
+        assert_eq!(
+            parse("verify 7 --input other"),
+            Err(String::from("option '--input' is not supported by verify"))
+        );
+        assert_eq!(
+            parse("bench --iterations many"),
+            Err(String::from(
+                "invalid value 'many' for '--iterations' (expected a number)"
+            ))
+        );
+        assert_eq!(
+            parse("run 25 --seed -1"),
+            Err(String::from(
+                "invalid value '-1' for '--seed' (expected a number or random)"
+            ))
+        );
+        assert_eq!(
+            parse("run 7 --verify=false"),
+            Err(String::from("option '--verify' does not take a value"))
+        );
+        assert_eq!(
+            parse("run 25 -v=1"),
+            Err(String::from("option '--verbose' does not take a value"))
+        );
+        assert_eq!(
+            parse("run 7 --warmup 2"),
+            Err(String::from("option '--warmup' is not supported by run"))
+        );
+        assert_eq!(
+            parse("run 7 first input other"),
+            Err(String::from("unexpected argument 'other'"))
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use common::{Answer, ParseError, Solution};
+use std::{
+    fs,
+    io::{self, Cursor, Read},
+    process::exit,
+    time::Instant,
+};
+
+mod answers;
+use answers::{load_known_answers, Verdict};
+mod bench;
+use bench::{bench_solution, format_table, load_baseline, save_baseline, Measurement};
+mod cli;
+use cli::{
+    default_input_file, BenchArguments, Command, InputSource, OutputFormat, Task, VerifyArguments,
+    USAGE,
+};
+mod report;
+use report::{Report, TaskReport};
+
+/// Calls the generic function with the solution of the given day as first type
+/// parameter.
+macro_rules! with_solution {
+    ($day:expr, $function:ident($($arg:expr),*)) => {
+        match $day {
+            1 => $function::<day_01::Day01>($($arg),*),
+            2 => $function::<day_02::Day02>($($arg),*),
+            3 => $function::<day_03::Day03>($($arg),*),
+            4 => $function::<day_04::Day04>($($arg),*),
+            5 => $function::<day_05::Day05>($($arg),*),
+            6 => $function::<day_06::Day06>($($arg),*),
+            7 => $function::<day_07::Day07>($($arg),*),
+            8 => $function::<day_08::Day08>($($arg),*),
+            9 => $function::<day_09::Day09>($($arg),*),
+            10 => $function::<day_10::Day10>($($arg),*),
+            11 => $function::<day_11::Day11>($($arg),*),
+            12 => $function::<day_12::Day12>($($arg),*),
+            13 => $function::<day_13::Day13>($($arg),*),
+            14 => $function::<day_14::Day14>($($arg),*),
+            15 => $function::<day_15::Day15>($($arg),*),
+            16 => $function::<day_16::Day16>($($arg),*),
+            17 => $function::<day_17::Day17>($($arg),*),
+            18 => $function::<day_18::Day18>($($arg),*),
+            19 => $function::<day_19::Day19>($($arg),*),
+            20 => $function::<day_20::Day20>($($arg),*),
+            21 => $function::<day_21::Day21>($($arg),*),
+            22 => $function::<day_22::Day22>($($arg),*),
+            23 => $function::<day_23::Day23>($($arg),*),
+            24 => $function::<day_24::Day24>($($arg),*),
+            25 => $function::<day_25::Day25>($($arg),*),
+            _ => unreachable!("The day is checked by the argument parser."),
+        }
+    };
+}
+
+/// Function that parses the input once and solves the given tasks with the default
+/// parameters (or the ones for the seed). The time of parsing and of every task is
+/// measured separately.
+fn solve<S: Solution>(
+    day: usize,
+    tasks: &[Task],
+    input: &[u8],
+    seed: Option<u64>,
+) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let parsed_input = S::parse(Cursor::new(input))?;
+    let parse_time = start.elapsed();
+    let parameters = seed.and_then(S::parameters_with_seed).unwrap_or_default();
+    let tasks = tasks
+        .iter()
+        .map(|&task| {
+            let start = Instant::now();
+            let answer = match task {
+                Task::First => S::solve_first_task(&parsed_input, &parameters),
+                Task::Second => S::solve_second_task(&parsed_input, &parameters),
+            };
+            TaskReport {
+                task,
+                answer,
+                solve_time: start.elapsed(),
+                verdict: None,
+            }
+        })
+        .collect();
+    Ok(Report {
+        day,
+        parse_time,
+        tasks,
+    })
+}
+
+/// Function that checks if the solution uses random numbers, so that it can be
+/// given a seed.
+fn takes_seed<S: Solution>() -> bool {
+    S::parameters_with_seed(0).is_some()
+}
+
+/// Function that reads the whole input, so that reading the file is not part of the
+/// parse time.
+fn read_input(input: &InputSource) -> io::Result<Vec<u8>> {
+    match input {
+        InputSource::Stdin => {
+            let mut buffer: Vec<u8> = Vec::new();
+            io::stdin().lock().read_to_end(&mut buffer)?;
+            Ok(buffer)
+        }
+        InputSource::File(filename) => fs::read(filename),
+    }
+}
+
+/// Function that reads the input, parses it and solves the given tasks. The returned
+/// error is the message for the user.
+fn run_day(
+    day: usize,
+    tasks: &[Task],
+    input: &InputSource,
+    seed: Option<u64>,
+) -> Result<Report, String> {
+    let content = read_input(input).map_err(|error| format!("cannot read {}: {}", input, error))?;
+    with_solution!(day, solve(day, tasks, &content, seed))
+        .map_err(|error| error.with_file(input.to_string()).to_string())
+}
+
+/// Function that compares the answers of the report with the known answers.
+fn verify_report(
+    report: &mut Report,
+    input: &InputSource,
+    answers_file: &str,
+) -> Result<(), String> {
+    let known_answers = load_known_answers(report.day, input, answers_file)?;
+    for task_report in report.tasks.iter_mut() {
+        task_report.verdict = Some(known_answers.verify(task_report.task, &task_report.answer));
+    }
+    Ok(())
+}
+
+/// Function that solves both tasks of the given days with their puzzle input and
+/// compares the answers with the known answers. It returns false if any answer is
+/// wrong or a day could not be solved.
+fn verify_days(arguments: &VerifyArguments) -> bool {
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for &day in arguments.days.iter() {
+        let input = InputSource::File(default_input_file(day));
+        let report =
+            run_day(day, &[Task::First, Task::Second], &input, None).and_then(|mut report| {
+                verify_report(&mut report, &input, &arguments.answers)?;
+                Ok(report)
+            });
+        let report = match report {
+            Ok(report) => report,
+            Err(message) => {
+                println!("Day {:02}: error: {}", day, message);
+                failed += 2;
+                continue;
+            }
+        };
+        for task_report in report.tasks.iter() {
+            let verdict = task_report.verdict.as_ref().unwrap_or(&Verdict::Unknown);
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail(_) => failed += 1,
+                // Without a known answer, a task that could not be solved still fails.
+                Verdict::Unknown if matches!(task_report.answer, Answer::Unsolved(_)) => {
+                    failed += 1
+                }
+                Verdict::Unknown => unknown += 1,
+            }
+            println!(
+                "Day {:02} {:?} task: {} ({})",
+                day, task_report.task, task_report.answer, verdict
+            );
+        }
+    }
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+    failed == 0
+}
+
+/// Function that benchmarks the given days with their puzzle input, prints the summary
+/// table and saves or compares the baseline. The returned error is the message for
+/// the user.
+fn bench_days(arguments: &BenchArguments) -> Result<(), String> {
+    // Load the baseline first, so that a wrong path does not waste a whole benchmark.
+    let baseline = match &arguments.baseline {
+        Some(path) => Some(load_baseline(path)?),
+        None => None,
+    };
+    let mut measurements: Vec<Measurement> = Vec::new();
+    for &day in arguments.days.iter() {
+        let input = InputSource::File(default_input_file(day));
+        eprintln!("Benchmarking day {:02} ...", day);
+        let content =
+            read_input(&input).map_err(|error| format!("cannot read {}: {}", input, error))?;
+        measurements.extend(
+            with_solution!(day, bench_solution(day, &content, &arguments.settings))
+                .map_err(|error| error.with_file(input.to_string()).to_string())?,
+        );
+    }
+    println!("{}", format_table(&measurements, baseline.as_ref()));
+    if let Some(path) = &arguments.save_baseline {
+        save_baseline(path, &measurements)?;
+        println!("Saved the baseline to {}.", path);
+    }
+    Ok(())
+}
+
+fn main() {
+    let arguments = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(Command::Run(arguments)) => arguments,
+        Ok(Command::Verify(arguments)) => {
+            if !verify_days(&arguments) {
+                exit(1);
+            }
+            return;
+        }
+        Ok(Command::Bench(arguments)) => {
+            if let Err(message) = bench_days(&arguments) {
+                eprintln!("error: {}", message);
+                exit(1);
+            }
+            return;
+        }
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            exit(2);
+        }
+    };
+    if arguments.seed.is_some() && !with_solution!(arguments.day, takes_seed()) {
+        eprintln!(
+            "error: option '--seed' is not supported by day {} (it does not use random numbers)",
+            arguments.day
+        );
+        exit(2);
+    }
+    let seed = arguments.seed.map(|seed| seed.choose());
+    if let Some(seed) = seed {
+        if arguments.verbose {
+            eprintln!("Seed: {}", seed);
+        }
+    }
+    let mut report = match run_day(arguments.day, &arguments.tasks, &arguments.input, seed) {
+        Ok(report) => report,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            exit(1);
+        }
+    };
+    if arguments.verify {
+        if let Err(message) = verify_report(&mut report, &arguments.input, &arguments.answers) {
+            eprintln!("error: {}", message);
+            exit(1);
+        }
+    }
+    match arguments.format {
+        OutputFormat::Text => println!("{}", report.to_text()),
+        OutputFormat::Json => println!("{}", report.to_json()),
+    }
+    if report.tasks.iter().any(|task_report| {
+        matches!(task_report.verdict, Some(Verdict::Fail(_)))
+            || matches!(task_report.answer, Answer::Unsolved(_))
+    }) {
+        exit(1);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{answers::Verdict, cli::Task};
+use common::Answer;
+use std::time::Duration;
+
+/// The answer of one task and the time it took to solve it.
+#[derive(Debug)]
+pub struct TaskReport {
+    pub task: Task,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    // Only set if the answer was compared with the known answer.
+    pub verdict: Option<Verdict>,
+}
+
+/// The result of one run of a day. The input is parsed once for all tasks.
+#[derive(Debug)]
+pub struct Report {
+    pub day: usize,
+    pub parse_time: Duration,
+    pub tasks: Vec<TaskReport>,
+}
+
+/// Function that escapes a string so that it can be used as a JSON string.
+fn to_json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl Report {
+    /// Function that creates the human readable output (one line per task).
+    pub fn to_text(&self) -> String {
+        self.tasks
+            .iter()
+            .map(|task_report| match &task_report.verdict {
+                Some(verdict) => format!(
+                    "{:?} task solution: {} ({})",
+                    task_report.task, task_report.answer, verdict
+                ),
+                None => format!(
+                    "{:?} task solution: {}",
+                    task_report.task, task_report.answer
+                ),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Function that creates a JSON array with one object per task. The answer is
+    /// always a string, so that large numbers do not lose precision, and the times
+    /// are given in nanoseconds. If the answers were verified, the verdict (and the
+    /// expected answer of a failed task) is added.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .tasks
+            .iter()
+            .map(|task_report| {
+                let verdict = match &task_report.verdict {
+                    Some(Verdict::Fail(expected)) => format!(
+                        ",\"verdict\":\"FAIL\",\"expected\":{}",
+                        to_json_string(expected)
+                    ),
+                    Some(verdict) => format!(",\"verdict\":\"{}\"", verdict.name()),
+                    None => String::new(),
+                };
+                format!(
+                    "{{\"day\":{},\"part\":{},\"answer\":{},\"parse_time_ns\":{},\"solve_time_ns\":{}{}}}",
+                    self.day,
+                    task_report.task.number(),
+                    to_json_string(&task_report.answer.to_string()),
+                    self.parse_time.as_nanos(),
+                    task_report.solve_time.as_nanos(),
+                    verdict
+                )
+            })
+            .collect();
+        format!("[{}]", entries.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_json() {
+        let report = Report {
+            day: 7,
+            parse_time: Duration::from_nanos(1500),
+            tasks: vec![
+                TaskReport {
+                    task: Task::First,
+                    answer: Answer::Unsigned(6440),
+                    solve_time: Duration::from_nanos(20),
+                    verdict: None,
+                },
+                TaskReport {
+                    task: Task::Second,
+                    answer: Answer::Text(String::from("a \"b\"\n")),
+                    solve_time: Duration::from_micros(3),
+                    verdict: Some(Verdict::Fail(String::from("c"))),
+                },
+            ],
+        };
+        assert_eq!(
+            report.to_json(),
+            concat!(
+                "[{\"day\":7,\"part\":1,\"answer\":\"6440\",\"parse_time_ns\":1500,\"solve_time_ns\":20},",
+                "{\"day\":7,\"part\":2,\"answer\":\"a \\\"b\\\"\\n\",\"parse_time_ns\":1500,\"solve_time_ns\":3000,",
+                "\"verdict\":\"FAIL\",\"expected\":\"c\"}]"
+            )
+        );
+        assert_eq!(
+            report.to_text(),
+            "First task solution: 6440\nSecond task solution: a \"b\"\n (FAIL, expected c)"
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+/// The answer to one task of a day. Most answers are numbers, but some puzzles
+/// want a negative number or a text as the answer. If the input is not supported by
+/// the solution, the reason is given instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    Unsolved(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Unsolved(reason) => write!(f, "unsolved ({})", reason),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() {
+        assert_eq!(Answer::from(42_usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-42_isize), Answer::Signed(-42));
+        assert_eq!(Answer::from("abc"), Answer::Text(String::from("abc")));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Unsigned(42).to_string(), "42");
+        assert_eq!(Answer::Signed(-42).to_string(), "-42");
+        assert_eq!(Answer::Text(String::from("abc")).to_string(), "abc");
+        assert_eq!(
+            Answer::Unsolved(String::from("no cycle")).to_string(),
+            "unsolved (no cycle)"
+        );
+    }
+}
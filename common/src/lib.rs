@@ -0,0 +1,9 @@
+mod answer;
+mod parse;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use parse::{parse_grid, parse_lines, read_lines, LineParser};
+pub use parse_error::ParseError;
+pub use solution::Solution;
@@ -0,0 +1,303 @@
+use crate::ParseError;
+use std::{io::BufRead, str::FromStr};
+
+/// Function that reads all lines. A line that cannot be read (e.g. because it is
+/// not valid UTF-8) is reported as a parse error of this line.
+pub fn read_lines<B: BufRead>(reader: B) -> Result<Vec<String>, ParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.map_err(|error| {
+                ParseError::new(idx + 1, 1, "a readable line").found(error.to_string())
+            })
+        })
+        .collect()
+}
+
+/// Function that parses every line with the given function. The line numbers of the
+/// returned errors are set to the line that failed.
+pub fn parse_lines<B, T, F>(reader: B, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    B: BufRead,
+    F: FnMut(String) -> Result<T, ParseError>,
+{
+    read_lines(reader)?
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line).map_err(|error| error.with_line(idx + 1)))
+        .collect()
+}
+
+/// Function that parses a map where every character is one cell. The function gets
+/// the character and returns the cell or None if the character is not allowed. All
+/// rows need to have the same length.
+pub fn parse_grid<B, T, F>(
+    reader: B,
+    expected: &str,
+    mut parse_cell: F,
+) -> Result<Vec<Vec<T>>, ParseError>
+where
+    B: BufRead,
+    F: FnMut(char) -> Option<T>,
+{
+    let lines = read_lines(reader)?;
+    let width = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+    let mut grid: Vec<Vec<T>> = Vec::with_capacity(lines.len());
+    for (row, line) in lines.iter().enumerate() {
+        let mut cells: Vec<T> = Vec::with_capacity(width);
+        for (col, c) in line.chars().enumerate() {
+            if col >= width {
+                return Err(ParseError::new(row + 1, col + 1, "end of line")
+                    .found(format!("'{}' (all rows need to be {} wide)", c, width)));
+            }
+            match parse_cell(c) {
+                Some(cell) => cells.push(cell),
+                None => {
+                    return Err(
+                        ParseError::new(row + 1, col + 1, expected).found(format!("'{}'", c))
+                    )
+                }
+            }
+        }
+        if cells.len() < width {
+            return Err(ParseError::new(row + 1, cells.len() + 1, expected)
+                .found(format!("end of line (all rows need to be {} wide)", width)));
+        }
+        grid.push(cells);
+    }
+    if grid.is_empty() {
+        return Err(ParseError::new(1, 1, expected).found("an empty input"));
+    }
+    Ok(grid)
+}
+
+/// A small parser for a single line that keeps track of the current column, so that
+/// every error can tell where exactly the line is not as expected.
+#[derive(Debug, Clone)]
+pub struct LineParser<'a> {
+    line: &'a str,
+    line_number: usize,
+    position: usize,
+}
+
+impl<'a> LineParser<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self {
+            line,
+            line_number: 1,
+            position: 0,
+        }
+    }
+
+    /// Function that sets the line number that is used for errors.
+    pub fn with_line_number(mut self, line_number: usize) -> Self {
+        self.line_number = line_number;
+        self
+    }
+
+    /// The current column (starting at 1).
+    pub fn column(&self) -> usize {
+        self.line[..self.position].chars().count() + 1
+    }
+
+    /// The part of the line that is not parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position == self.line.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Function that creates an error at the current column. What was found is the
+    /// next character (or the end of the line).
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line_number, self.column(), expected).found(match self.peek() {
+            Some(c) => format!("'{}'", c),
+            None => String::from("end of line"),
+        })
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Function that consumes the token if the rest of the line starts with it.
+    pub fn accept(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Function that consumes the token or returns an error if it is not there.
+    pub fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        if self.accept(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("'{}'", token)))
+        }
+    }
+
+    /// Function that consumes the next character if it is one of the given characters.
+    pub fn one_of(&mut self, characters: &str) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(c) if characters.contains(c) => {
+                self.position += c.len_utf8();
+                Ok(c)
+            }
+            _ => Err(self.error(format!(
+                "one of {}",
+                characters
+                    .chars()
+                    .map(|c| format!("'{}'", c))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// Function that consumes characters as long as the predicate holds. It fails if
+    /// there is not at least one such character.
+    pub fn take_while<P: Fn(char) -> bool>(
+        &mut self,
+        expected: &str,
+        predicate: P,
+    ) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let length = rest
+            .char_indices()
+            .find(|&(_, c)| !predicate(c))
+            .map(|(idx, _)| idx)
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error(expected));
+        }
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    /// Function that skips leading whitespace and consumes a word of letters and digits.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        self.take_while("a name", |c| c.is_ascii_alphanumeric())
+    }
+
+    /// Function that skips leading whitespace and parses a (possibly negative) number.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.clone();
+        let rest = self.rest();
+        let sign_length = if rest.starts_with('-') || rest.starts_with('+') {
+            1
+        } else {
+            0
+        };
+        let digits = rest[sign_length..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits == 0 {
+            return Err(start.error("a number"));
+        }
+        let number = &rest[..sign_length + digits];
+        self.position += number.len();
+        number.parse::<T>().map_err(|_| {
+            start
+                .error("a number")
+                .found(format!("'{}' (out of range)", number))
+        })
+    }
+
+    /// Function that expects that the whole line was parsed (trailing whitespace is ok).
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_line_parser() {
+        let mut parser = LineParser::new("px{a<2006:qkq}");
+        assert_eq!(parser.word(), Ok("px"));
+        assert_eq!(parser.expect("{"), Ok(()));
+        assert_eq!(parser.one_of("xmas"), Ok('a'));
+        assert_eq!(parser.one_of("<>"), Ok('<'));
+        assert_eq!(parser.number::<usize>(), Ok(2006));
+        assert_eq!(parser.expect(":"), Ok(()));
+        assert_eq!(parser.word(), Ok("qkq"));
+        assert_eq!(parser.expect("}"), Ok(()));
+        assert_eq!(parser.end(), Ok(()));
+    }
+
+    #[test]
+    fn test_line_parser_errors() {
+        let mut parser = LineParser::new("19, 13 @ -2").with_line_number(4);
+        assert_eq!(parser.number::<i64>(), Ok(19));
+        assert_eq!(parser.expect(","), Ok(()));
+        assert_eq!(parser.number::<i64>(), Ok(13));
+        assert_eq!(
+            parser.expect(","),
+            Err(ParseError::new(4, 7, "','").found("' '"))
+        );
+        parser.skip_whitespace();
+        assert_eq!(
+            parser.number::<i64>(),
+            Err(ParseError::new(4, 8, "a number").found("'@'"))
+        );
+        assert_eq!(parser.expect("@"), Ok(()));
+        assert_eq!(parser.number::<i64>(), Ok(-2));
+        assert_eq!(
+            parser.number::<i64>(),
+            Err(ParseError::new(4, 12, "a number").found("end of line"))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let reader = Cursor::new("1\n2\nx\n");
+        assert_eq!(
+            parse_lines(reader, |line| LineParser::new(&line).number::<usize>()),
+            Err(ParseError::new(3, 1, "a number").found("'x'"))
+        );
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let parse_cell = |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        };
+        assert_eq!(
+            parse_grid(Cursor::new(".#\n#."), "'.' or '#'", parse_cell),
+            Ok(vec![vec![false, true], vec![true, false]])
+        );
+        assert_eq!(
+            parse_grid(Cursor::new(".#\n#?"), "'.' or '#'", parse_cell),
+            Err(ParseError::new(2, 2, "'.' or '#'").found("'?'"))
+        );
+        assert_eq!(
+            parse_grid(Cursor::new(".#\n#"), "'.' or '#'", parse_cell),
+            Err(ParseError::new(2, 2, "'.' or '#'")
+                .found("end of line (all rows need to be 2 wide)"))
+        );
+    }
+}
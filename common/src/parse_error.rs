@@ -0,0 +1,84 @@
+use std::{error::Error, fmt};
+
+/// Error that is returned if the puzzle input does not look like expected. It knows
+/// where the problem is (file, line and column, all starting at 1) and what was
+/// expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: None,
+        }
+    }
+
+    /// Function that sets what was found instead of the expected token.
+    pub fn found(mut self, found: impl Into<String>) -> Self {
+        self.found = Some(found.into());
+        self
+    }
+
+    /// Function that sets the line number. Parsers of a single line do not know in
+    /// which line of the input they are, so the caller sets it afterwards.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Function that sets the name of the file the input was read from.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.expected
+        )?;
+        if let Some(found) = &self.found {
+            write!(f, ", found {}", found)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::new(3, 7, "a number").to_string(),
+            "<input>:3:7: expected a number"
+        );
+        assert_eq!(
+            ParseError::new(1, 2, "'<' or '>'")
+                .found("'='")
+                .with_line(12)
+                .with_file("day-19/input")
+                .to_string(),
+            "day-19/input:12:2: expected '<' or '>', found '='"
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{Answer, ParseError};
+use std::io::BufRead;
+
+/// The solution of one day. The input is parsed once and can then be used to
+/// solve both tasks.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+    /// Values that are not part of the puzzle input but change between the
+    /// examples and the real puzzle (e.g. the number of steps to take). The default
+    /// values are the ones of the real puzzle. Use `()` if there are none.
+    type Parameters: Default;
+
+    /// Function that parses the puzzle input. Malformed input results in an error
+    /// that tells where the input is not as expected.
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError>;
+
+    /// Function that returns the parameters for a run whose random numbers are
+    /// generated from the seed. Solutions without random numbers return None, so that
+    /// the runner can reject the seed.
+    fn parameters_with_seed(_seed: u64) -> Option<Self::Parameters> {
+        None
+    }
+
+    fn solve_first_task(input: &Self::Input, parameters: &Self::Parameters) -> Answer;
+
+    fn solve_second_task(input: &Self::Input, parameters: &Self::Parameters) -> Answer;
+}
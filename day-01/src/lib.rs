@@ -1,14 +1,5 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Default)]
-enum Task {
-    #[default]
-    First,
-    Second,
-}
+use common::{read_lines, Answer, ParseError, Solution};
+use std::io::BufRead;
 
 /// Function to calculate the calibration value.
 fn calculate_calibration_value(line: String) -> usize {
@@ -23,13 +14,13 @@ fn calculate_calibration_value(line: String) -> usize {
 }
 
 /// Function needed by the second task to parse written numbers to numbers (eight -> 8).
-fn words_to_numbers(line: String) -> String {
+fn words_to_numbers(line: &str) -> String {
     let mut result = String::from("");
     let mut index = 0;
     while index < line.len() {
         let partial_line = &line[index..];
         let first_character = partial_line.chars().next().unwrap();
-        if first_character.is_digit(10) == true {
+        if first_character.is_ascii_digit() {
             result.push(first_character);
         } else {
             for word in [
@@ -55,65 +46,60 @@ fn words_to_numbers(line: String) -> String {
                 }
             }
         }
-        index = index + 1;
+        index += 1;
     }
     result
 }
 
-fn solve_first_task<B: BufRead>(reader: B) -> usize {
-    reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| line.chars().filter(|c| c.is_digit(10)).collect())
-        .map(calculate_calibration_value)
-        .sum()
-}
+pub struct Day01;
 
-fn solve_second_task<B: BufRead>(reader: B) -> usize {
-    reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| words_to_numbers(line))
-        .map(calculate_calibration_value)
-        .sum()
-}
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Parameters = ();
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        read_lines(reader)
+    }
 
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        match task {
-            Task::First => solve_first_task(reader),
-            Task::Second => solve_second_task(reader),
-        }
-    )
+    fn solve_first_task(lines: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        lines
+            .iter()
+            .map(|line| line.chars().filter(|c| c.is_ascii_digit()).collect())
+            .map(calculate_calibration_value)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn solve_second_task(lines: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        lines
+            .iter()
+            .map(|line| words_to_numbers(line))
+            .map(calculate_calibration_value)
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader), 142);
+        assert_eq!(
+            Day01::solve_first_task(&Day01::parse(reader).unwrap(), &()),
+            Answer::Unsigned(142)
+        );
     }
 
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input2.test").expect("Input file not found."));
-        assert_eq!(solve_second_task(reader), 281);
+        assert_eq!(
+            Day01::solve_second_task(&Day01::parse(reader).unwrap(), &()),
+            Answer::Unsigned(281)
+        );
     }
 }
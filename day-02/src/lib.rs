@@ -0,0 +1,166 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::io::BufRead;
+
+const MAX_CUBES_RED: usize = 12;
+const MAX_CUBES_GREEN: usize = 13;
+const MAX_CUBES_BLUE: usize = 14;
+
+#[derive(Debug)]
+pub enum CubeColor {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A game consists of multiple reveals and each reveal of multiple draws like "1 blue".
+pub type Game = Vec<Vec<(usize, CubeColor)>>;
+
+/// Function that parses strings like "1 blue" to (usize, CubeColor).
+fn parse_draw(parser: &mut LineParser) -> Result<(usize, CubeColor), ParseError> {
+    let number_of_cubes: usize = parser.number()?;
+    parser.skip_whitespace();
+    let color_of_cube = if parser.accept("red") {
+        CubeColor::Red
+    } else if parser.accept("green") {
+        CubeColor::Green
+    } else if parser.accept("blue") {
+        CubeColor::Blue
+    } else {
+        return Err(parser.error("'red', 'green' or 'blue'"));
+    };
+    Ok((number_of_cubes, color_of_cube))
+}
+
+/// Function that parses a whole line of the input (one game).
+fn parse_game(line: String) -> Result<Game, ParseError> {
+    // Example line: Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    let mut parser = LineParser::new(&line);
+    parser.expect("Game")?;
+    parser.number::<usize>()?;
+    parser.expect(":")?;
+    let mut game: Game = Vec::new();
+    loop {
+        let mut reveal = Vec::new();
+        loop {
+            reveal.push(parse_draw(&mut parser)?);
+            if !parser.accept(",") {
+                break;
+            }
+        }
+        game.push(reveal);
+        if !parser.accept(";") {
+            break;
+        }
+    }
+    parser.end()?;
+    Ok(game)
+}
+
+/// Function for task 1 that checks if the number of drawed cubes is less or equal than
+/// the amount of available cubes of this color.
+fn check_amount_of_cubes(draw: &(usize, CubeColor)) -> bool {
+    let (number_of_cubes, color_of_cube) = draw;
+    *number_of_cubes
+        <= match color_of_cube {
+            CubeColor::Red => MAX_CUBES_RED,
+            CubeColor::Green => MAX_CUBES_GREEN,
+            CubeColor::Blue => MAX_CUBES_BLUE,
+        }
+}
+
+/// Function for task 1 that checks if a single reveal of one game is possible.
+fn check_if_reveal_is_possible(reveal: &[(usize, CubeColor)]) -> bool {
+    reveal.iter().all(check_amount_of_cubes)
+}
+
+/// Function for task 1 that checks if a game is possible.
+fn check_if_game_is_possible(game: &Game) -> bool {
+    game.iter()
+        .all(|reveal| check_if_reveal_is_possible(reveal))
+}
+
+/// Function for task 2 that calculates the power of a cube set.
+fn get_power_of_cube_set(game: &Game) -> usize {
+    let mut number_of_red_cubes = 0;
+    let mut number_of_green_cubes = 0;
+    let mut number_of_blue_cubes = 0;
+
+    for reveal in game.iter() {
+        for (number_of_cubes, color_of_cube) in reveal.iter() {
+            match color_of_cube {
+                CubeColor::Red => number_of_red_cubes = number_of_red_cubes.max(*number_of_cubes),
+                CubeColor::Green => {
+                    number_of_green_cubes = number_of_green_cubes.max(*number_of_cubes)
+                }
+                CubeColor::Blue => {
+                    number_of_blue_cubes = number_of_blue_cubes.max(*number_of_cubes)
+                }
+            }
+        }
+    }
+
+    number_of_red_cubes * number_of_green_cubes * number_of_blue_cubes
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, parse_game)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(games: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        games
+            .iter()
+            .enumerate()
+            .filter(|(_, game)| check_if_game_is_possible(game))
+            .map(|(idx, _)| idx + 1)
+            .sum::<usize>()
+            .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(games: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        games
+            .iter()
+            .map(get_power_of_cube_set)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day02::solve_first_task(&Day02::parse(reader).unwrap(), &()),
+            Answer::Unsigned(8)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_game(String::from("Game 1: 3 blue, 4 purple")).unwrap_err(),
+            ParseError::new(1, 19, "'red', 'green' or 'blue'").found("'p'")
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day02::solve_second_task(&Day02::parse(reader).unwrap(), &()),
+            Answer::Unsigned(2286)
+        );
+    }
+}
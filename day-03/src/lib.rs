@@ -0,0 +1,150 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Position};
+use std::{collections::HashSet, io::BufRead};
+
+fn is_part_number_symbol(c: char) -> bool {
+    !c.is_alphanumeric() && c != '.'
+}
+
+/// Function that marks every cell that is next to a symbol (or is a symbol itself).
+fn generate_lookup_part_number(schematic: &Grid<char>) -> Grid<bool> {
+    let mut lookup_part_number = Grid::new(schematic.width(), schematic.height(), false);
+    for (pos, &c) in schematic.iter() {
+        if is_part_number_symbol(c) {
+            lookup_part_number[pos] = true;
+            for neighbor in schematic.neighbors8(pos) {
+                lookup_part_number[neighbor] = true;
+            }
+        }
+    }
+    lookup_part_number
+}
+
+fn find_gears(schematic: &Grid<char>) -> usize {
+    let mut lookup_numbers: Grid<Option<usize>> =
+        Grid::new(schematic.width(), schematic.height(), None);
+    for (row, line) in schematic.rows().enumerate() {
+        let mut current_number = String::from("");
+        for (col, &c) in line.iter().enumerate() {
+            if c.is_ascii_digit() {
+                current_number.push(c);
+            } else if !current_number.is_empty() {
+                let number: usize = current_number.parse().unwrap();
+                for rev_idx in 1..=current_number.len() {
+                    lookup_numbers[Position::new(col - rev_idx, row)] = Some(number);
+                }
+                current_number.clear();
+            }
+        }
+        // Catch the last one.
+        if !current_number.is_empty() {
+            let number: usize = current_number.parse().unwrap();
+            for rev_idx in 1..=current_number.len() {
+                lookup_numbers[Position::new(line.len() - rev_idx, row)] = Some(number);
+            }
+        }
+    }
+    let mut result: usize = 0;
+    for (pos, &c) in schematic.iter() {
+        if c == '*' {
+            // Look around and get the values.
+            let numbers: HashSet<usize> = schematic
+                .neighbors8(pos)
+                .filter_map(|neighbor| lookup_numbers[neighbor])
+                .collect();
+            if numbers.len() == 2 {
+                result += numbers.iter().product::<usize>();
+            }
+        }
+    }
+    result
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        // Every character is allowed, but all lines need to have the same length.
+        Grid::from_reader(reader, "a character", Some)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(schematic: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        let lookup_part_number = generate_lookup_part_number(schematic);
+        let mut part_numbers: Vec<usize> = Vec::new();
+        for (row, line) in schematic.rows().enumerate() {
+            let mut current_number = String::from("");
+            let mut is_part_number = false;
+            for (col, &c) in line.iter().enumerate() {
+                if c.is_ascii_digit() {
+                    current_number.push(c);
+                    is_part_number = is_part_number || lookup_part_number[Position::new(col, row)];
+                } else if !current_number.is_empty() {
+                    if is_part_number {
+                        part_numbers.push(current_number.parse().unwrap());
+                    }
+                    current_number.clear();
+                    is_part_number = false;
+                }
+            }
+            // Catch the last one.
+            if !current_number.is_empty() && is_part_number {
+                part_numbers.push(current_number.parse().unwrap());
+            }
+        }
+        part_numbers.iter().sum::<usize>().into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(schematic: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        find_gears(schematic).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day03::solve_first_task(&Day03::parse(reader).unwrap(), &()),
+            Answer::Unsigned(4361)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day03::solve_second_task(&Day03::parse(reader).unwrap(), &()),
+            Answer::Unsigned(467835)
+        );
+    }
+
+    #[test]
+    fn test_lookup_generation() {
+        let input_str = "....\n.*..\n....\n....";
+        let schematic = Day03::parse(Cursor::new(input_str)).unwrap();
+        let generated_lookup = generate_lookup_part_number(&schematic);
+        println!(
+            "{}",
+            generated_lookup.render(|&value| if value { '#' } else { '.' })
+        );
+        assert_eq!(
+            generated_lookup,
+            Grid::from_rows(vec![
+                vec![true, true, true, false],
+                vec![true, true, true, false],
+                vec![true, true, true, false],
+                vec![false, false, false, false]
+            ])
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::{collections::HashSet, io::BufRead};
+
+/// Function that reads numbers until the parser reaches the given token or the end of the line.
+fn parse_numbers(parser: &mut LineParser, until: &str) -> Result<HashSet<usize>, ParseError> {
+    let mut numbers: HashSet<usize> = HashSet::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.is_at_end() || parser.rest().starts_with(until) {
+            return Ok(numbers);
+        }
+        numbers.insert(parser.number()?);
+    }
+}
+
+fn extract_numbers(line: String) -> Result<(HashSet<usize>, HashSet<usize>), ParseError> {
+    // Example line: Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    let mut parser = LineParser::new(&line);
+    parser.expect("Card")?;
+    parser.number::<usize>()?;
+    parser.expect(":")?;
+    let winning_numbers = parse_numbers(&mut parser, "|")?;
+    parser.expect("|")?;
+    let drawn_numbers = parse_numbers(&mut parser, "|")?;
+    parser.end()?;
+    Ok((winning_numbers, drawn_numbers))
+}
+
+fn get_number_of_wins(line: String) -> Result<usize, ParseError> {
+    let (winning_hashset, drawn_hashset) = extract_numbers(line)?;
+    let common_numbers: HashSet<_> = winning_hashset.intersection(&drawn_hashset).collect();
+    Ok(common_numbers.len())
+}
+
+fn calculate_points(number_of_wins: usize) -> usize {
+    if number_of_wins == 0 {
+        0
+    } else {
+        i32::pow(2, (number_of_wins - 1) as u32) as usize
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The number of wins of each scratchcard.
+    type Input = Vec<usize>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, get_number_of_wins)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(wins: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        wins.iter()
+            .cloned()
+            .map(calculate_points)
+            .sum::<usize>()
+            .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(wins: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        let mut result: Vec<usize> = vec![1; wins.len()];
+        for (row, number_of_wins) in wins.iter().enumerate() {
+            for scratchcard in 1..=*number_of_wins {
+                if row + scratchcard < result.len() {
+                    result[row + scratchcard] += result[row];
+                }
+            }
+        }
+        result.iter().sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day04::solve_first_task(&Day04::parse(reader).unwrap(), &()),
+            Answer::Unsigned(13)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day04::solve_second_task(&Day04::parse(reader).unwrap(), &()),
+            Answer::Unsigned(30)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use common::{read_lines, LineParser, ParseError};
 use itertools::Itertools;
 use std::io::BufRead;
 use std::ops::Range;
@@ -15,21 +16,23 @@ struct Mapper {
 
 #[derive(Debug)]
 pub struct Almanac {
-    seeds: Vec<Range<usize>>,
+    /// The raw numbers of the seed line, their meaning depends on the seed mode.
+    seed_numbers: Vec<usize>,
     mappers: Vec<Mapper>,
 }
 
 impl RangeMapper {
-    fn from_line(line: String) -> Self {
+    fn from_line(line: String) -> Result<Self, ParseError> {
         // Example line: 50 98 2
-        let parts: Vec<usize> = line
-            .split_whitespace()
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect();
-        Self {
-            destination_range: (parts[0]..parts[0] + parts[2]),
-            source_range: (parts[1]..parts[1] + parts[2]),
-        }
+        let mut parser = LineParser::new(&line);
+        let destination_start: usize = parser.number()?;
+        let source_start: usize = parser.number()?;
+        let length: usize = parser.number()?;
+        parser.end()?;
+        Ok(Self {
+            destination_range: (destination_start..destination_start + length),
+            source_range: (source_start..source_start + length),
+        })
     }
 
     fn convert_number(&self, input: usize) -> usize {
@@ -86,8 +89,9 @@ impl Mapper {
         }
     }
 
-    fn add_range_mapper(&mut self, line: String) {
-        self.range_mappers.push(RangeMapper::from_line(line));
+    fn add_range_mapper(&mut self, line: String) -> Result<(), ParseError> {
+        self.range_mappers.push(RangeMapper::from_line(line)?);
+        Ok(())
     }
 
     fn convert_ranges(&self, input_ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
@@ -135,54 +139,34 @@ impl AlmanacParser {
 impl Almanac {
     fn new() -> Self {
         Almanac {
-            seeds: Vec::new(),
+            seed_numbers: Vec::new(),
             mappers: Vec::new(),
         }
     }
 
-    pub fn from_reader<B: BufRead>(reader: B, seed_mode: SeedMode) -> Self {
+    pub fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
         let mut almanac = Almanac::new();
         let mut parser_state: AlmanacParser = AlmanacParser::ReadSeed;
-        for line in reader.lines() {
-            let line = line.unwrap();
+        for (idx, line) in read_lines(reader)?.into_iter().enumerate() {
+            let mut parser = LineParser::new(&line).with_line_number(idx + 1);
             match parser_state {
                 AlmanacParser::ReadSeed => {
                     // Example line: seeds: 79 14 55 13
-                    match seed_mode {
-                        SeedMode::Single => {
-                            almanac.seeds = line
-                                .split(':')
-                                .nth(1)
-                                .unwrap()
-                                .trim()
-                                .split_whitespace()
-                                .map(|s| s.parse::<usize>().unwrap())
-                                .map(|range_start| range_start..range_start + 1)
-                                .collect();
-                        }
-                        SeedMode::Range => {
-                            almanac.seeds = line
-                                .split(':')
-                                .nth(1)
-                                .unwrap()
-                                .trim()
-                                .split_whitespace()
-                                .map(|s| s.parse::<usize>().unwrap())
-                                .chunks(2)
-                                .into_iter()
-                                .map(|mut chunk| {
-                                    let range_start = chunk.next().unwrap();
-                                    let range_length = chunk.next().unwrap();
-                                    range_start..range_start + range_length
-                                })
-                                .collect()
-                        }
+                    parser.expect("seeds:")?;
+                    while !parser.is_at_end() {
+                        almanac.seed_numbers.push(parser.number()?);
+                        parser.skip_whitespace();
                     }
                 }
                 AlmanacParser::EmptyLine => {
-                    // Do nothing.
+                    parser.end()?;
                 }
                 AlmanacParser::MapperName => {
+                    // Example line: seed-to-soil map:
+                    parser
+                        .take_while("the name of a map", |c| c.is_ascii_alphabetic() || c == '-')?;
+                    parser.expect(" map:")?;
+                    parser.end()?;
                     // Create a new mapper.
                     almanac.mappers.push(Mapper::new());
                 }
@@ -193,20 +177,42 @@ impl Almanac {
                     } else {
                         // Add a new RangeMapper to the last appended Mapper.
                         if let Some(last_mapper) = almanac.mappers.last_mut() {
-                            last_mapper.add_range_mapper(line);
-                        } else {
-                            println!("Error: Cannot add range mapper.");
+                            last_mapper
+                                .add_range_mapper(line)
+                                .map_err(|error| error.with_line(idx + 1))?;
                         }
                     }
                 }
             }
             parser_state = parser_state.next_state();
         }
-        almanac
+        Ok(almanac)
+    }
+
+    /// Function that interprets the seed numbers depending on the seed mode.
+    fn get_seeds(&self, seed_mode: SeedMode) -> Vec<Range<usize>> {
+        match seed_mode {
+            SeedMode::Single => self
+                .seed_numbers
+                .iter()
+                .map(|&range_start| range_start..range_start + 1)
+                .collect(),
+            SeedMode::Range => self
+                .seed_numbers
+                .iter()
+                .chunks(2)
+                .into_iter()
+                .map(|mut chunk| {
+                    let range_start = *chunk.next().unwrap();
+                    let range_length = *chunk.next().unwrap();
+                    range_start..range_start + range_length
+                })
+                .collect(),
+        }
     }
 
-    pub fn get_min_location(&self) -> usize {
-        let mut ranges_for_next_run = self.seeds.clone();
+    pub fn get_min_location(&self, seed_mode: SeedMode) -> usize {
+        let mut ranges_for_next_run = self.get_seeds(seed_mode);
         for mapper in &self.mappers {
             ranges_for_next_run = mapper.convert_ranges(ranges_for_next_run);
         }
@@ -219,8 +225,10 @@ impl Almanac {
 }
 
 #[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
 mod tests {
     use super::*;
+    use std::io::Cursor;
 
     #[test]
     fn test_range_converter_overlapping() {
@@ -306,10 +314,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error() {
+        let reader = Cursor::new("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n");
+        assert_eq!(
+            Almanac::from_reader(reader).unwrap_err(),
+            ParseError::new(5, 6, "a number").found("end of line")
+        );
+    }
+
     #[test]
     fn test_simple_almanac() {
         let almanac = Almanac {
-            seeds: vec![20..40, 50..60],
+            seed_numbers: vec![20, 20, 50, 10],
             mappers: vec![
                 Mapper {
                     range_mappers: vec![
@@ -335,6 +352,6 @@ mod tests {
                 },
             ],
         };
-        assert_eq!(almanac.get_min_location(), 10);
+        assert_eq!(almanac.get_min_location(SeedMode::Range), 10);
     }
 }
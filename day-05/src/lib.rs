@@ -0,0 +1,50 @@
+use common::{Answer, ParseError, Solution};
+use std::io::BufRead;
+
+mod almanac;
+use almanac::*;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Almanac::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(almanac: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        almanac.get_min_location(SeedMode::Single).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(almanac: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        almanac.get_min_location(SeedMode::Range).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day05::solve_first_task(&Day05::parse(reader).unwrap(), &()),
+            Answer::Unsigned(35)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day05::solve_second_task(&Day05::parse(reader).unwrap(), &()),
+            Answer::Unsigned(46)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use common::{read_lines, Answer, LineParser, ParseError, Solution};
+use std::io::BufRead;
+
+fn get_number_of_winning_cases(time: usize, distance: usize) -> usize {
+    let first_term = time as f64 * 0.5;
+    let sqrt_term = f64::sqrt(f64::powi(first_term, 2) - distance as f64);
+    if sqrt_term.is_nan() {
+        // There is no root.
+        // This is not necessary for our case but what so ever.
+        return 0;
+    }
+    let mut x1 = first_term - sqrt_term;
+    let mut x2 = first_term + sqrt_term;
+    // Check if the value is equal to the integer part. If so add / subtract one.
+    if x1.trunc() == x1 {
+        x1 += 1.0;
+    }
+    if x2.trunc() == x2 {
+        x2 -= 1.0;
+    }
+    // Only keep the integer parts by ceiling / flooring.
+    x2.floor() as usize - x1.ceil() as usize + 1
+}
+
+/// Function that checks that the line looks like "Time:      7  15   30" (with the given
+/// label) and returns the number of values.
+fn check_line(line: &str, line_number: usize, label: &str) -> Result<usize, ParseError> {
+    let mut parser = LineParser::new(line).with_line_number(line_number);
+    parser.expect(label)?;
+    let mut number_of_values = 0;
+    while !parser.is_at_end() {
+        parser.number::<usize>()?;
+        parser.skip_whitespace();
+        number_of_values += 1;
+    }
+    Ok(number_of_values)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The line with the times and the line with the distances.
+    type Input = (String, String);
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        let mut lines = read_lines(reader)?.into_iter();
+        let times = lines.next().unwrap_or_default();
+        let distances = lines.next().unwrap_or_default();
+        let number_of_times = check_line(&times, 1, "Time:")?;
+        let number_of_distances = check_line(&distances, 2, "Distance:")?;
+        if number_of_times != number_of_distances {
+            return Err(ParseError::new(
+                2,
+                distances.len() + 1,
+                format!("{} distances", number_of_times),
+            )
+            .found(format!("{} distances", number_of_distances)));
+        }
+        Ok((times, distances))
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(
+        (times, distances): &Self::Input,
+        _parameters: &Self::Parameters,
+    ) -> Answer {
+        let times = times
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(str::parse::<usize>)
+            .map(Result::unwrap);
+        let distances = distances
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(str::parse::<usize>)
+            .map(Result::unwrap);
+        times
+            .zip(distances)
+            .map(|(time, distance)| get_number_of_winning_cases(time, distance))
+            .fold(1, |mut product, value| {
+                product *= value;
+                product
+            })
+            .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(
+        (times, distances): &Self::Input,
+        _parameters: &Self::Parameters,
+    ) -> Answer {
+        let time = times
+            .bytes()
+            .filter(|byte| byte.is_ascii_digit())
+            .fold(0_usize, |number, digit| {
+                number * 10 + (digit - b'0') as usize
+            });
+        let distance = distances
+            .bytes()
+            .filter(|byte| byte.is_ascii_digit())
+            .fold(0_usize, |number, digit| {
+                number * 10 + (digit - b'0') as usize
+            });
+        get_number_of_winning_cases(time, distance).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day06::solve_first_task(&Day06::parse(reader).unwrap(), &()),
+            Answer::Unsigned(288)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day06::solve_second_task(&Day06::parse(reader).unwrap(), &()),
+            Answer::Unsigned(71503)
+        );
+    }
+
+    #[test]
+    fn test_number_of_winning_cases_odd_time() {
+        assert_eq!(get_number_of_winning_cases(7, 9), 4);
+    }
+
+    #[test]
+    fn test_number_of_winning_cases_impossible() {
+        assert_eq!(get_number_of_winning_cases(1, 20), 0);
+    }
+
+    #[test]
+    fn test_number_of_winning_cases_all() {
+        assert_eq!(get_number_of_winning_cases(100, 1), 99);
+    }
+}
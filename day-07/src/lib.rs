@@ -1,8 +1,5 @@
-use std::{
-    cmp::Ordering,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::{cmp::Ordering, io::BufRead};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Eq, Ord)]
 enum Task {
@@ -23,7 +20,7 @@ enum HandType {
     FiveOfAKind,
 }
 
-#[derive(Debug, PartialEq, Eq, Ord)]
+#[derive(Debug, PartialEq, Eq)]
 struct Hand {
     hand: String,
     cards: [usize; 13],
@@ -102,11 +99,8 @@ impl Hand {
         }
     }
 
-    fn from_line(line: String, task: Task) -> Self {
-        // Example line: 32T3K 765
-        let mut parts = line.split_ascii_whitespace();
-        let hand = parts.next().unwrap().to_string();
-        let bid = parts.next().unwrap().parse::<usize>().unwrap();
+    fn new(hand: &str, bid: usize, task: Task) -> Self {
+        let hand = hand.to_string();
         let mut cards = [0; 13];
         for c in hand.bytes() {
             cards[Hand::char_to_card_index(c, task)] += 1;
@@ -122,8 +116,8 @@ impl Hand {
     }
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self.hand_type == other.hand_type {
             // The hand types are equal so we will look at the hand.
             for (c1, c2) in self.hand.bytes().zip(other.hand.bytes()) {
@@ -131,21 +125,39 @@ impl PartialOrd for Hand {
                     continue;
                 }
                 return Hand::char_to_card_index(c1, self.task)
-                    .partial_cmp(&Hand::char_to_card_index(c2, other.task));
+                    .cmp(&Hand::char_to_card_index(c2, other.task));
             }
-            None
+            Ordering::Equal
         } else {
             // The hand types can be simply compared.
-            self.hand_type.partial_cmp(&other.hand_type)
+            self.hand_type.cmp(&other.hand_type)
         }
     }
 }
 
-fn solve_task<B: BufRead>(reader: B, task: Task) -> usize {
-    let mut hands: Vec<Hand> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| Hand::from_line(line, task))
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Function that parses a line like "32T3K 765" into the hand and its bid.
+fn parse_hand_and_bid(line: String) -> Result<(String, usize), ParseError> {
+    let mut parser = LineParser::new(&line);
+    let mut hand = String::new();
+    for _ in 0..5 {
+        hand.push(parser.one_of("23456789TJQKA")?);
+    }
+    parser.expect(" ")?;
+    let bid = parser.number::<usize>()?;
+    parser.end()?;
+    Ok((hand, bid))
+}
+
+fn solve_task(hands_and_bids: &[(String, usize)], task: Task) -> usize {
+    let mut hands: Vec<Hand> = hands_and_bids
+        .iter()
+        .map(|(hand, bid)| Hand::new(hand, *bid, task))
         .collect();
     hands.sort();
     hands
@@ -155,35 +167,47 @@ fn solve_task<B: BufRead>(reader: B, task: Task) -> usize {
         .sum()
 }
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
-
-    println!("{:?} task solution: {:?}", task, solve_task(reader, task));
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(String, usize)>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, parse_hand_and_bid)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(hands_and_bids: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task(hands_and_bids, Task::First).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(hands_and_bids: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task(hands_and_bids, Task::Second).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task(reader, Task::First), 6440);
+        assert_eq!(
+            solve_task(&Day07::parse(reader).unwrap(), Task::First),
+            6440
+        );
     }
 
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task(reader, Task::Second), 5905);
+        assert_eq!(
+            solve_task(&Day07::parse(reader).unwrap(), Task::Second),
+            5905
+        );
     }
 }
@@ -0,0 +1,173 @@
+use common::{read_lines, Answer, LineParser, ParseError, Solution};
+use gcd::Gcd;
+use graph::{Graph, NodeId};
+use std::io::BufRead;
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn from_line(line: String) -> Result<Self, ParseError> {
+        // Example line: AAA = (BBB, CCC)
+        let mut parser = LineParser::new(&line);
+        let name = parser.word()?.to_string();
+        parser.expect(" = (")?;
+        let left = parser.word()?.to_string();
+        parser.expect(", ")?;
+        let right = parser.word()?.to_string();
+        parser.expect(")")?;
+        parser.end()?;
+        Ok(Node { name, left, right })
+    }
+}
+
+/// The network of nodes. Every node has two edges: the first one to the left node and
+/// the second one to the right node.
+pub struct Network {
+    // The index of the edge to follow (0 for L, 1 for R).
+    instructions: Vec<usize>,
+    graph: Graph,
+}
+
+impl Network {
+    /// Function that returns the node we get to from the node with the instruction.
+    fn next_node(&self, node: NodeId, idx_instruction: usize) -> NodeId {
+        self.graph.edges(node)[self.instructions[idx_instruction]].to
+    }
+}
+
+/// Function that counts the steps from AAA to ZZZ.
+fn count_steps_to_zzz(network: &Network) -> usize {
+    let start = network.graph.node_id("AAA").expect("There is no node AAA.");
+    let end = network.graph.node_id("ZZZ").expect("There is no node ZZZ.");
+    let mut result = 0;
+    let mut idx_instruction = 0;
+    let mut current_node = start;
+    while current_node != end {
+        result += 1;
+        current_node = network.next_node(current_node, idx_instruction);
+        idx_instruction = (idx_instruction + 1) % network.instructions.len();
+    }
+    result
+}
+
+/// Function that counts the steps until all ghosts are on nodes ending with Z at the same time.
+fn count_steps_for_ghosts(network: &Network) -> usize {
+    let graph = &network.graph;
+    let mut idx_instruction = 0;
+    let mut steps_to_reach_target: Vec<usize> = Vec::new();
+    let mut current_nodes: Vec<NodeId> = graph
+        .nodes()
+        .filter(|&node| graph.name(node).ends_with('A'))
+        .collect();
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        current_nodes = current_nodes
+            .iter()
+            .map(|&node| network.next_node(node, idx_instruction))
+            .collect();
+        let mut idx = 0;
+        while idx < current_nodes.len() {
+            if graph.name(current_nodes[idx]).ends_with('Z') {
+                current_nodes.remove(idx);
+                steps_to_reach_target.push(steps);
+            } else {
+                idx += 1;
+            }
+        }
+        if current_nodes.is_empty() {
+            break;
+        }
+        idx_instruction = (idx_instruction + 1) % network.instructions.len();
+    }
+    steps_to_reach_target
+        .into_iter()
+        .reduce(|total, number| number / total.gcd_binary(number) * total)
+        .unwrap()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(reader)?;
+        // Example line: LLR
+        let first_line = lines.first().cloned().unwrap_or_default();
+        let mut parser = LineParser::new(&first_line);
+        let instructions = parser
+            .take_while("'L' or 'R'", |c| c == 'L' || c == 'R')?
+            .chars()
+            .map(|c| if c == 'L' { 0 } else { 1 })
+            .collect();
+        parser.end()?;
+        let mut graph = Graph::new_directed();
+        for (idx, line) in lines.into_iter().enumerate().skip(1) {
+            // The instructions and the nodes are separated by an empty line.
+            if idx == 1 {
+                LineParser::new(&line).with_line_number(idx + 1).end()?;
+                continue;
+            }
+            let node = Node::from_line(line).map_err(|error| error.with_line(idx + 1))?;
+            let id = graph.add_node(&node.name);
+            let left = graph.add_node(&node.left);
+            let right = graph.add_node(&node.right);
+            graph.add_edge(id, left);
+            graph.add_edge(id, right);
+        }
+        Ok(Network {
+            instructions,
+            graph,
+        })
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(network: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        count_steps_to_zzz(network).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(network: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        count_steps_for_ghosts(network).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task_input_1() {
+        let reader = BufReader::new(File::open("./input1_1.test").expect("Input file not found."));
+        assert_eq!(
+            Day08::solve_first_task(&Day08::parse(reader).unwrap(), &()),
+            Answer::Unsigned(2)
+        );
+    }
+
+    #[test]
+    fn test_first_task_input_2() {
+        let reader = BufReader::new(File::open("./input1_2.test").expect("Input file not found."));
+        assert_eq!(
+            Day08::solve_first_task(&Day08::parse(reader).unwrap(), &()),
+            Answer::Unsigned(6)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input2.test").expect("Input file not found."));
+        assert_eq!(
+            Day08::solve_second_task(&Day08::parse(reader).unwrap(), &()),
+            Answer::Unsigned(6)
+        );
+    }
+}
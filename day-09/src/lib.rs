@@ -0,0 +1,108 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::io::BufRead;
+
+/// Function that parses a line of the input into its values.
+fn parse_values(line: String) -> Result<Vec<isize>, ParseError> {
+    // Example line: 0 3 6 9 12 15
+    let mut parser = LineParser::new(&line);
+    let mut values: Vec<isize> = vec![parser.number()?];
+    parser.skip_whitespace();
+    while !parser.is_at_end() {
+        values.push(parser.number()?);
+        parser.skip_whitespace();
+    }
+    Ok(values)
+}
+
+fn solve_first_task_line(initial_values: &[isize]) -> isize {
+    let mut all_values: Vec<Vec<isize>> = vec![initial_values.to_vec()];
+    // Create the vectors.
+    while !all_values.last().unwrap().iter().all(|&value| value == 0) {
+        let differences: Vec<isize> = all_values
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        all_values.push(differences);
+    }
+    // Pass the sum from the bottom to the top.
+    all_values
+        .iter()
+        .map(|values| values.last().unwrap_or(&0))
+        .sum()
+}
+
+fn solve_second_task_line(initial_values: &[isize]) -> isize {
+    let mut all_values: Vec<Vec<isize>> = vec![initial_values.to_vec()];
+    // Create the vectors.
+    while !all_values.last().unwrap().iter().all(|&value| value == 0) {
+        let differences: Vec<isize> = all_values
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        all_values.push(differences);
+    }
+    // Pass the sum from the bottom to the top.
+    let result = all_values
+        .iter()
+        .rev()
+        .map(|values| values.first().unwrap_or(&0))
+        .fold(0_isize, |diff, &value| value - diff);
+    result
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<isize>>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, parse_values)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(histories: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        histories
+            .iter()
+            .map(|values| solve_first_task_line(values))
+            .sum::<isize>()
+            .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(histories: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        histories
+            .iter()
+            .map(|values| solve_second_task_line(values))
+            .sum::<isize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day09::solve_first_task(&Day09::parse(reader).unwrap(), &()),
+            Answer::Signed(114)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day09::solve_second_task(&Day09::parse(reader).unwrap(), &()),
+            Answer::Signed(2)
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+use regex::Regex;
+use std::io::BufRead;
+
+/// Function that returns the two directions that the pipe connects.
+fn get_pipe_connections(c: char) -> Option<[Direction; 2]> {
+    // | is a vertical pipe connecting north and south.
+    // - is a horizontal pipe connecting east and west.
+    // L is a 90-degree bend connecting north and east.
+    // J is a 90-degree bend connecting north and west.
+    // 7 is a 90-degree bend connecting south and west.
+    // F is a 90-degree bend connecting south and east.
+    // . is ground; there is no pipe in this tile.
+    // S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+    match c {
+        '|' => Some([Direction::Up, Direction::Down]),
+        '-' => Some([Direction::Left, Direction::Right]),
+        'L' => Some([Direction::Up, Direction::Right]),
+        'J' => Some([Direction::Up, Direction::Left]),
+        '7' => Some([Direction::Down, Direction::Left]),
+        'F' => Some([Direction::Down, Direction::Right]),
+        _ => None,
+    }
+}
+
+/// Function that returns the direction in which we leave the pipe if we entered it
+/// walking into the given direction.
+fn get_next_direction(c: char, direction: Direction) -> Direction {
+    let [first, second] = get_pipe_connections(c).unwrap();
+    if first == direction.reverse() {
+        second
+    } else {
+        first
+    }
+}
+
+/// Function that finds the pipe below the start position, which is the one that
+/// connects the two neighboring pipes that lead to the start.
+fn get_start_pipe(sketch: &Grid<char>, start_position: Position) -> char {
+    let connected: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|&direction| {
+            sketch
+                .neighbor(start_position, direction)
+                .and_then(|pos| get_pipe_connections(sketch[pos]))
+                .is_some_and(|connections| connections.contains(&direction.reverse()))
+        })
+        .collect();
+    "|-LJ7F"
+        .chars()
+        .find(|&c| {
+            let mut connections = get_pipe_connections(c).unwrap();
+            connections.sort();
+            connections[..] == connected[..]
+        })
+        .unwrap()
+}
+
+fn get_pipe_path(sketch: &mut Grid<char>, replace_start_pipe: bool) -> Vec<Position> {
+    // Find the start position (the parser made sure that there is one).
+    let start_position = sketch.find(|&c| c == 'S').unwrap();
+    // Determine in which direction to walk at the start.
+    let start_pipe = get_start_pipe(sketch, start_position);
+    let mut direction = get_pipe_connections(start_pipe).unwrap()[0];
+    // Extract all positions that are part of the loop.
+    let mut pos = start_position;
+    let mut path: Vec<Position> = Vec::new();
+    loop {
+        path.push(pos);
+        // The loop is closed, so it never leaves the map.
+        pos = sketch.neighbor(pos, direction).unwrap();
+        if pos == start_position {
+            break;
+        }
+        direction = get_next_direction(sketch[pos], direction);
+    }
+    if replace_start_pipe {
+        sketch[start_position] = start_pipe;
+    }
+    path
+}
+
+/// Function that counts the tiles enclosed by the loop.
+fn count_enclosed_tiles(sketch: &Grid<char>) -> usize {
+    let mut sketch = sketch.clone();
+    let path = get_pipe_path(&mut sketch, true);
+    let mut is_on_path = Grid::new(sketch.width(), sketch.height(), false);
+    for &pos in path.iter() {
+        is_on_path[pos] = true;
+    }
+    let mut result = 0;
+    // Patterns to replace the horizontal lines with corners but keep their length.
+    let pattern_l7 = Regex::new(r"L-*7").unwrap();
+    let pattern_lj = Regex::new(r"L-*J").unwrap();
+    let pattern_f7 = Regex::new(r"F-*7").unwrap();
+    let pattern_fj = Regex::new(r"F-*J").unwrap();
+    // Ray casting algorithm.
+    for (row, line) in sketch.rows().enumerate() {
+        let line: String = line.iter().collect();
+        // L---7 becomes |....
+        let line = pattern_l7.replace_all(&line, |caps: &regex::Captures| {
+            let matched_length = caps[0].len();
+            "|".to_string() + &".".repeat(matched_length - 1)
+        });
+        // L---J becomes ||...
+        let line = pattern_lj.replace_all(&line, |caps: &regex::Captures| {
+            let matched_length = caps[0].len();
+            "||".to_string() + &".".repeat(matched_length - 2)
+        });
+        // F---7 becomes ||...
+        let line = pattern_f7.replace_all(&line, |caps: &regex::Captures| {
+            let matched_length = caps[0].len();
+            "||".to_string() + &".".repeat(matched_length - 2)
+        });
+        // F---J becomes |....
+        let line = pattern_fj.replace_all(&line, |caps: &regex::Captures| {
+            let matched_length = caps[0].len();
+            "|".to_string() + &".".repeat(matched_length - 1)
+        });
+        // Count the intersections with the polygon and if a tile that is not on the path
+        // occurs after an odd number of intersections, then its inside the polygon.
+        let mut number_of_intersections = 0;
+        for (col, c) in line.chars().enumerate() {
+            if is_on_path[Position::new(col, row)] {
+                // Only the '|' counts as a wall, since we already replaced the other
+                // characters by a '|'.
+                if c == '|' {
+                    number_of_intersections += 1;
+                }
+            } else if number_of_intersections % 2 == 1 {
+                result += 1;
+            }
+        }
+    }
+    result
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<char>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        let sketch = Grid::from_reader(
+            reader,
+            "a pipe ('|', '-', 'L', 'J', '7', 'F'), '.' or 'S'",
+            |c| "|-LJ7F.S".contains(c).then_some(c),
+        )?;
+        if sketch.find(|&c| c == 'S').is_none() {
+            return Err(ParseError::new(
+                sketch.height(),
+                sketch.width() + 1,
+                "the start position 'S'",
+            )
+            .found("end of input"));
+        }
+        Ok(sketch)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(sketch: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        let path = get_pipe_path(&mut sketch.clone(), false);
+        (path.len() / 2).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(sketch: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        count_enclosed_tiles(sketch).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task_example_1_with_ground() {
+        let reader = BufReader::new(File::open("./input1_1.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_first_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(4)
+        );
+    }
+
+    #[test]
+    fn test_first_task_example_1_with_pipes() {
+        let reader = BufReader::new(File::open("./input1_2.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_first_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(4)
+        );
+    }
+
+    #[test]
+    fn test_first_task_example_2_with_ground() {
+        let reader = BufReader::new(File::open("./input1_3.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_first_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(8)
+        );
+    }
+
+    #[test]
+    fn test_first_task_example_2_with_pipes() {
+        let reader = BufReader::new(File::open("./input1_4.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_first_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(8)
+        );
+    }
+
+    #[test]
+    fn test_second_task_example_1() {
+        let reader = BufReader::new(File::open("./input2_1.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_second_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(4)
+        );
+    }
+
+    #[test]
+    fn test_second_task_example_2() {
+        let reader = BufReader::new(File::open("./input2_2.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_second_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(8)
+        );
+    }
+
+    #[test]
+    fn test_second_task_example_3() {
+        let reader = BufReader::new(File::open("./input2_3.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_second_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(10)
+        );
+    }
+
+    #[test]
+    fn test_second_task_example_4() {
+        let reader = BufReader::new(File::open("./input1_1.test").expect("Input file not found."));
+        assert_eq!(
+            Day10::solve_second_task(&Day10::parse(reader).unwrap(), &()),
+            Answer::Unsigned(1)
+        );
+    }
+}
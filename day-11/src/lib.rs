@@ -0,0 +1,146 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Position};
+use std::io::BufRead;
+
+/// The image of the universe before the expansion. A cell is true if it is a galaxy.
+pub struct Image {
+    map: Grid<bool>,
+}
+
+fn find_galaxies<B: BufRead>(reader: B) -> Result<Image, ParseError> {
+    let map = Grid::from_reader(reader, "'.' or '#'", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+    Ok(Image { map })
+}
+
+/// Function that calculates for each index how far it moves when every empty row / column
+/// is replaced by `expansion_number` empty ones.
+fn get_expansion_offsets(is_filled: &[bool], expansion_number: usize) -> Vec<usize> {
+    let mut total_move = 0;
+    is_filled
+        .iter()
+        .map(|is_filled| {
+            if !is_filled {
+                total_move += expansion_number - 1;
+            }
+            total_move
+        })
+        .collect()
+}
+
+fn expand_galaxies(image: &Image, expansion_number: usize) -> Vec<Position> {
+    let lookup_col: Vec<bool> = (0..image.map.width())
+        .map(|x| image.map.column(x).any(|&is_galaxy| is_galaxy))
+        .collect();
+    let lookup_row: Vec<bool> = image.map.rows().map(|row| row.contains(&true)).collect();
+    let move_col = get_expansion_offsets(&lookup_col, expansion_number);
+    let move_row = get_expansion_offsets(&lookup_row, expansion_number);
+    image
+        .map
+        .iter()
+        .filter(|(_, &is_galaxy)| is_galaxy)
+        .map(|(pos, _)| Position::new(pos.x + move_col[pos.x], pos.y + move_row[pos.y]))
+        .collect()
+}
+
+fn calculate_distance(pos1: Position, pos2: Position) -> usize {
+    pos1.x.abs_diff(pos2.x) + pos1.y.abs_diff(pos2.y)
+}
+
+fn calculate_sum_of_all_distances(galaxies: Vec<Position>) -> usize {
+    let mut total_distance = 0;
+    for (idx, galaxy) in galaxies.iter().enumerate() {
+        for other in galaxies.iter().skip(idx + 1) {
+            total_distance += calculate_distance(*galaxy, *other);
+        }
+    }
+    total_distance
+}
+
+pub struct Parameters {
+    /// How many empty rows / columns replace a single empty one in the second task.
+    pub expansion_factor: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            expansion_factor: 1_000_000,
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+    type Parameters = Parameters;
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        find_galaxies(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(image: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        calculate_sum_of_all_distances(expand_galaxies(image, 2)).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(image: &Self::Input, parameters: &Self::Parameters) -> Answer {
+        calculate_sum_of_all_distances(expand_galaxies(image, parameters.expansion_factor)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_distance_calculation() {
+        assert_eq!(
+            calculate_distance(Position::new(1, 6), Position::new(5, 11)),
+            9
+        );
+    }
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day11::solve_first_task(&Day11::parse(reader).unwrap(), &Parameters::default()),
+            Answer::Unsigned(374)
+        );
+    }
+
+    #[test]
+    fn test_second_task_10_times_larger() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day11::solve_second_task(
+                &Day11::parse(reader).unwrap(),
+                &Parameters {
+                    expansion_factor: 10
+                }
+            ),
+            Answer::Unsigned(1030)
+        );
+    }
+
+    #[test]
+    fn test_second_task_100_times_larger() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day11::solve_second_task(
+                &Day11::parse(reader).unwrap(),
+                &Parameters {
+                    expansion_factor: 100
+                }
+            ),
+            Answer::Unsigned(8410)
+        );
+    }
+}
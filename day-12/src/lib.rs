@@ -1,15 +1,5 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Default)]
-enum Task {
-    #[default]
-    First,
-    Second,
-}
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::{collections::HashMap, io::BufRead};
 
 #[derive(Debug, Copy, Clone)]
 enum SpringCondition {
@@ -18,8 +8,8 @@ enum SpringCondition {
     Unknown,
 }
 
-#[derive(Debug)]
-struct ConditionRecord {
+#[derive(Debug, Clone)]
+pub struct ConditionRecord {
     line: Vec<char>,
     spring_conditions: Vec<SpringCondition>,
     damaged_springs_group_size: Vec<usize>,
@@ -27,48 +17,34 @@ struct ConditionRecord {
 }
 
 impl ConditionRecord {
-    fn from_line(line: String) -> Self {
+    fn from_line(line: String) -> Result<Self, ParseError> {
         // Example line: ???.### 1,1,3
+        let mut parser = LineParser::new(&line);
+        let springs = parser.take_while("'.', '#' or '?'", |c| ".#?".contains(c))?;
         let mut spring_conditions: Vec<SpringCondition> = Vec::new();
-        let mut damaged_springs_group_size: Vec<usize> = Vec::new();
         let mut position_of_unknown_conditions: Vec<usize> = Vec::new();
-        let mut current_group_size = 0;
-        for (idx, c) in line.chars().enumerate() {
-            match c {
-                '.' => {
-                    spring_conditions.push(SpringCondition::Operational);
-                }
-                '#' => {
-                    spring_conditions.push(SpringCondition::Damaged);
-                }
-                '?' => {
-                    spring_conditions.push(SpringCondition::Unknown);
+        for (idx, c) in springs.chars().enumerate() {
+            spring_conditions.push(match c {
+                '.' => SpringCondition::Operational,
+                '#' => SpringCondition::Damaged,
+                _ => {
                     position_of_unknown_conditions.push(idx);
+                    SpringCondition::Unknown
                 }
-                c if c.is_ascii_digit() => {
-                    current_group_size = current_group_size * 10
-                        + str::parse::<usize>(c.to_string().as_str()).unwrap();
-                }
-                ',' => {
-                    damaged_springs_group_size.push(current_group_size);
-                    current_group_size = 0;
-                }
-                _ => {}
-            }
+            });
+        }
+        parser.expect(" ")?;
+        let mut damaged_springs_group_size: Vec<usize> = vec![parser.number()?];
+        while parser.accept(",") {
+            damaged_springs_group_size.push(parser.number()?);
         }
-        // Catch the last group size.
-        damaged_springs_group_size.push(current_group_size);
-        Self {
-            line: line
-                .split_ascii_whitespace()
-                .nth(0)
-                .unwrap()
-                .chars()
-                .collect(),
+        parser.end()?;
+        Ok(Self {
+            line: springs.chars().collect(),
             spring_conditions,
             damaged_springs_group_size,
             position_of_unknown_conditions,
-        }
+        })
     }
 
     fn unfold(&mut self) {
@@ -116,6 +92,7 @@ impl ConditionRecord {
         .concat();
     }
 
+    #[cfg(test)]
     fn check_if_conditions_equal_group_sizes(
         spring_conditions: Vec<SpringCondition>,
         expected_group_sizes: &Vec<usize>,
@@ -143,6 +120,7 @@ impl ConditionRecord {
         actual_group_sizes == *expected_group_sizes
     }
 
+    #[cfg(test)]
     fn get_number_of_different_arrangements_brute_force(&self) -> usize {
         let mut result = 0;
         for i in 0..(1 << self.position_of_unknown_conditions.len()) {
@@ -160,8 +138,7 @@ impl ConditionRecord {
             if ConditionRecord::check_if_conditions_equal_group_sizes(
                 spring_conditions,
                 &self.damaged_springs_group_size,
-            ) == true
-            {
+            ) {
                 result += 1;
             }
         }
@@ -169,8 +146,8 @@ impl ConditionRecord {
     }
 
     fn get_number_of_different_arrangements_substring(
-        substring: &Vec<char>,
-        groups_to_fulfill: &Vec<usize>,
+        substring: &[char],
+        groups_to_fulfill: &[usize],
         lookup: &mut HashMap<(Vec<char>, Vec<usize>), usize>,
     ) -> usize {
         // Check if there are still characters. If not, this is a solution, but only if there are also no more groups to fulfill.
@@ -183,42 +160,38 @@ impl ConditionRecord {
         }
 
         match substring[0] {
-            '.' => {
-                return ConditionRecord::get_number_of_different_arrangements_substring(
-                    &substring[1..].to_vec(),
-                    groups_to_fulfill,
-                    lookup,
-                );
-            }
-            '#' => {
-                return ConditionRecord::get_number_of_different_arrangements_found_group_begin(
-                    substring,
-                    groups_to_fulfill,
-                    lookup,
-                );
-            }
+            '.' => ConditionRecord::get_number_of_different_arrangements_substring(
+                &substring[1..],
+                groups_to_fulfill,
+                lookup,
+            ),
+            '#' => ConditionRecord::get_number_of_different_arrangements_found_group_begin(
+                substring,
+                groups_to_fulfill,
+                lookup,
+            ),
             '?' => {
-                return ConditionRecord::get_number_of_different_arrangements_substring(
-                    &substring[1..].to_vec(),
+                ConditionRecord::get_number_of_different_arrangements_substring(
+                    &substring[1..],
                     groups_to_fulfill,
                     lookup,
                 ) + ConditionRecord::get_number_of_different_arrangements_found_group_begin(
                     substring,
                     groups_to_fulfill,
                     lookup,
-                );
+                )
             }
             _ => unreachable!("Unallowed character detected."),
         }
     }
 
     fn get_number_of_different_arrangements_found_group_begin(
-        substring: &Vec<char>,
-        groups_to_fulfill: &Vec<usize>,
+        substring: &[char],
+        groups_to_fulfill: &[usize],
         lookup: &mut HashMap<(Vec<char>, Vec<usize>), usize>,
     ) -> usize {
         // Check if we already got this case. If so, reuse it.
-        if let Some(&result) = lookup.get(&(substring.clone(), groups_to_fulfill.clone())) {
+        if let Some(&result) = lookup.get(&(substring.to_vec(), groups_to_fulfill.to_vec())) {
             return result;
         }
         // If there is currently a group begin but there is no more a group to fulfill, this is not a solution.
@@ -232,8 +205,8 @@ impl ConditionRecord {
             return 0;
         }
         // Now check for the next wanted group length. There should only be '#' or '?', so no '.'.
-        let current_group_length = groups_to_fulfill[0] as usize;
-        if substring[1..current_group_length].iter().any(|&c| c == '.') {
+        let current_group_length = groups_to_fulfill[0];
+        if substring[1..current_group_length].contains(&'.') {
             return 0;
         }
         // It is also necessary to check if there is another '#' behind our current group. This is not allowed, this would make the group too large.
@@ -251,12 +224,12 @@ impl ConditionRecord {
 
         // Ok, we can fulfill this (currently, maybe the next groups are not possible but that is not the problem of this call).
         let result = ConditionRecord::get_number_of_different_arrangements_substring(
-            &substring[(current_group_length + 1)..].to_vec(),
-            &groups_to_fulfill[1..].to_vec(),
+            &substring[(current_group_length + 1)..],
+            &groups_to_fulfill[1..],
             lookup,
         );
         // Save the result.
-        lookup.insert((substring.clone(), groups_to_fulfill.clone()), result);
+        lookup.insert((substring.to_vec(), groups_to_fulfill.to_vec()), result);
         result
     }
 
@@ -264,103 +237,96 @@ impl ConditionRecord {
         &self,
         lookup: &mut HashMap<(Vec<char>, Vec<usize>), usize>,
     ) -> usize {
-        return ConditionRecord::get_number_of_different_arrangements_substring(
+        ConditionRecord::get_number_of_different_arrangements_substring(
             &self.line,
             &self.damaged_springs_group_size,
             lookup,
-        );
+        )
     }
 }
 
-fn solve_first_task<B: BufRead>(reader: B) -> usize {
-    let mut lookup: HashMap<(Vec<char>, Vec<usize>), usize> = HashMap::new();
-    reader
-        .lines()
-        .map(Result::unwrap)
-        .map(ConditionRecord::from_line)
-        .map(|record| record.get_number_of_different_arrangements(&mut lookup))
-        .sum()
-}
+pub struct Day12;
 
-fn solve_second_task<B: BufRead>(reader: B) -> usize {
-    let mut lookup: HashMap<(Vec<char>, Vec<usize>), usize> = HashMap::new();
-    reader
-        .lines()
-        .map(Result::unwrap)
-        .map(ConditionRecord::from_line)
-        .map(|mut record| {
-            record.unfold();
-            record.get_number_of_different_arrangements(&mut lookup)
-        })
-        .sum()
-}
+impl Solution for Day12 {
+    type Input = Vec<ConditionRecord>;
+    type Parameters = ();
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, ConditionRecord::from_line)
+    }
 
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        match task {
-            Task::First => solve_first_task(reader),
-            Task::Second => solve_second_task(reader),
-        }
-    )
+    /// Function that solves the first task.
+    fn solve_first_task(records: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        let mut lookup: HashMap<(Vec<char>, Vec<usize>), usize> = HashMap::new();
+        records
+            .iter()
+            .map(|record| record.get_number_of_different_arrangements(&mut lookup))
+            .sum::<usize>()
+            .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(records: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        let mut lookup: HashMap<(Vec<char>, Vec<usize>), usize> = HashMap::new();
+        records
+            .iter()
+            .cloned()
+            .map(|mut record| {
+                record.unfold();
+                record.get_number_of_different_arrangements(&mut lookup)
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader), 21);
+        assert_eq!(
+            Day12::solve_first_task(&Day12::parse(reader).unwrap(), &()),
+            Answer::Unsigned(21)
+        );
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_1() {
-        let record = ConditionRecord::from_line(String::from("???.### 1,1,3"));
+        let record = ConditionRecord::from_line(String::from("???.### 1,1,3")).unwrap();
         assert_eq!(record.get_number_of_different_arrangements_brute_force(), 1)
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_2() {
-        let record = ConditionRecord::from_line(String::from(".??..??...?##. 1,1,3"));
+        let record = ConditionRecord::from_line(String::from(".??..??...?##. 1,1,3")).unwrap();
         assert_eq!(record.get_number_of_different_arrangements_brute_force(), 4)
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_3() {
-        let record = ConditionRecord::from_line(String::from("?#?#?#?#?#?#?#? 1,3,1,6"));
+        let record = ConditionRecord::from_line(String::from("?#?#?#?#?#?#?#? 1,3,1,6")).unwrap();
         assert_eq!(record.get_number_of_different_arrangements_brute_force(), 1)
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_4() {
-        let record = ConditionRecord::from_line(String::from("????.#...#... 4,1,1"));
+        let record = ConditionRecord::from_line(String::from("????.#...#... 4,1,1")).unwrap();
         assert_eq!(record.get_number_of_different_arrangements_brute_force(), 1)
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_5() {
-        let record = ConditionRecord::from_line(String::from("????.######..#####. 1,6,5"));
+        let record = ConditionRecord::from_line(String::from("????.######..#####. 1,6,5")).unwrap();
         assert_eq!(record.get_number_of_different_arrangements_brute_force(), 4)
     }
 
     #[test]
     fn test_number_of_different_arrangements_example_6() {
-        let record = ConditionRecord::from_line(String::from("?###???????? 3,2,1"));
+        let record = ConditionRecord::from_line(String::from("?###???????? 3,2,1")).unwrap();
         assert_eq!(
             record.get_number_of_different_arrangements_brute_force(),
             10
@@ -370,12 +336,15 @@ mod tests {
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_second_task(reader), 525152);
+        assert_eq!(
+            Day12::solve_second_task(&Day12::parse(reader).unwrap(), &()),
+            Answer::Unsigned(525152)
+        );
     }
 
     #[test]
     fn test_unfolding() {
-        let mut record = ConditionRecord::from_line(String::from(".# 1"));
+        let mut record = ConditionRecord::from_line(String::from(".# 1")).unwrap();
         record.unfold();
         assert_eq!(record.position_of_unknown_conditions, vec![2, 5, 8, 11])
     }
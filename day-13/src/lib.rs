@@ -0,0 +1,133 @@
+use common::{read_lines, Answer, LineParser, ParseError, Solution};
+use grid::Grid;
+use std::io::BufRead;
+
+fn find_number_of_smudges(line1: &[char], line2: &[char]) -> usize {
+    line1
+        .iter()
+        .zip(line2.iter())
+        .map(|(c1, c2)| if c1 == c2 { 0 } else { 1 })
+        .sum()
+}
+
+fn find_reflection(pattern: &Grid<char>, number_of_allowed_smudges: usize) -> usize {
+    for idx in 0..(pattern.height() - 1) {
+        let mut number_of_found_smudges = 0;
+        // Walk to the outside.
+        let mut look_up = idx;
+        let mut look_down = idx + 1;
+        loop {
+            number_of_found_smudges +=
+                find_number_of_smudges(pattern.row(look_up), pattern.row(look_down));
+            if number_of_found_smudges > number_of_allowed_smudges {
+                // Too many mismatches found.
+                break;
+            }
+            // Check if we can still compare further.
+            if look_up == 0 || look_down == pattern.height() - 1 {
+                // No error occured, this is really mirrored.
+                // But only if the number of smudges is correct.
+                if number_of_found_smudges == number_of_allowed_smudges {
+                    return idx + 1;
+                } else {
+                    break;
+                }
+            }
+            // Next comparison.
+            look_up -= 1;
+            look_down += 1;
+        }
+    }
+    0
+}
+
+fn get_reflection_score(pattern: &Grid<char>, number_of_allowed_smudges: usize) -> usize {
+    let mut result = 0;
+    // Vertical.
+    result += find_reflection(pattern, number_of_allowed_smudges) * 100;
+    // Horizontal.
+    let pattern_t = pattern.transpose();
+    result += find_reflection(&pattern_t, number_of_allowed_smudges);
+    result
+}
+
+/// Function that splits the input into the patterns separated by empty lines.
+fn parse_patterns<B: BufRead>(reader: B) -> Result<Vec<Grid<char>>, ParseError> {
+    let mut patterns: Vec<Grid<char>> = Vec::new();
+    let mut pattern: Vec<Vec<char>> = Vec::new();
+    for (idx, line) in read_lines(reader)?.into_iter().enumerate() {
+        if line.is_empty() {
+            if !pattern.is_empty() {
+                patterns.push(Grid::from_rows(pattern));
+                pattern = Vec::new();
+            }
+        } else {
+            let mut parser = LineParser::new(&line).with_line_number(idx + 1);
+            parser.take_while("'.' or '#'", |c| c == '.' || c == '#')?;
+            parser.end()?;
+            // All rows of a pattern need to have the same length.
+            if let Some(first_line) = pattern.first() {
+                if first_line.len() != line.len() {
+                    return Err(ParseError::new(
+                        idx + 1,
+                        line.len().min(first_line.len()) + 1,
+                        format!("a row of length {}", first_line.len()),
+                    )
+                    .found(format!("a row of length {}", line.len())));
+                }
+            }
+            pattern.push(line.chars().collect());
+        }
+    }
+    // Catch the last one.
+    if !pattern.is_empty() {
+        patterns.push(Grid::from_rows(pattern));
+    }
+    Ok(patterns)
+}
+
+fn solve_task(patterns: &[Grid<char>], number_of_allowed_smudges: usize) -> usize {
+    patterns
+        .iter()
+        .map(|pattern| get_reflection_score(pattern, number_of_allowed_smudges))
+        .sum()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_patterns(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(patterns: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task(patterns, 0).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(patterns: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task(patterns, 1).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day13::parse(reader).unwrap(), 0), 405);
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day13::parse(reader).unwrap(), 1), 400);
+    }
+}
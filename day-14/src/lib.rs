@@ -0,0 +1,162 @@
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Debug, Clone)]
+pub struct Platform {
+    pattern: Grid<char>,
+}
+
+impl Platform {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        Ok(Self {
+            pattern: Grid::from_reader(reader, "'O', '#' or '.'", |c| {
+                "O#.".contains(c).then_some(c)
+            })?,
+        })
+    }
+
+    fn transpose(&mut self) {
+        self.pattern = self.pattern.transpose();
+    }
+
+    fn mirror_horizontal(&mut self) {
+        self.pattern = self.pattern.mirror_horizontal();
+    }
+
+    fn mirror_vertical(&mut self) {
+        self.pattern = self.pattern.mirror_vertical();
+    }
+
+    fn let_line_roll(line: &mut [char]) {
+        let mut next_free_position = 0;
+        for idx in 0..line.len() {
+            match line[idx] {
+                'O' => {
+                    line[idx] = '.';
+                    line[next_free_position] = 'O';
+                    next_free_position += 1;
+                }
+                '#' => {
+                    next_free_position = idx + 1;
+                }
+                '.' => {}
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    fn let_rocks_roll_to_the_left(&mut self) {
+        for row in 0..self.pattern.height() {
+            Platform::let_line_roll(self.pattern.row_mut(row));
+        }
+    }
+
+    fn count_weight_on_northern_support_beams(&self) -> usize {
+        let mut result = 0;
+        for line in self.pattern.rows() {
+            for (idx, &c) in line.iter().enumerate() {
+                if c == 'O' {
+                    result += line.len() - idx;
+                }
+            }
+        }
+        result
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        println!("{}", self.pattern);
+    }
+}
+
+/// Function that tilts the platform north once and returns the load.
+fn get_load_after_tilt(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    platform.transpose();
+    platform.let_rocks_roll_to_the_left();
+    platform.count_weight_on_northern_support_beams()
+}
+
+/// Function that spins the platform a billion cycles and returns the load.
+fn get_load_after_spin_cycles(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    let mut lookup: HashMap<Grid<char>, usize> = HashMap::new();
+    // Create the east orientation.
+    platform.mirror_vertical();
+    platform.mirror_horizontal();
+    // Starting from the northern orientation, repeat the cycles.
+    let total_number_of_cycles = 4 * 1_000_000_000;
+    let mut found_inner_cycle = false;
+    let mut cycle = 0;
+    while cycle < total_number_of_cycles {
+        platform.transpose();
+        platform.mirror_vertical();
+        platform.let_rocks_roll_to_the_left();
+        // Check if we already saw this state.
+        if !found_inner_cycle {
+            if let Some(&old_cycle) = lookup.get(&platform.pattern) {
+                // Nice, we already saw this. We can skip some.
+                let cycle_length = cycle - old_cycle;
+                let cycle_repeat = (total_number_of_cycles - old_cycle) / cycle_length;
+                cycle = old_cycle + cycle_repeat * cycle_length + 1;
+                found_inner_cycle = true;
+            } else {
+                lookup.insert(platform.pattern.clone(), cycle);
+                cycle += 1;
+            }
+        } else {
+            cycle += 1;
+        }
+    }
+    // Get it back to an orientation that can calculate the value.
+    // North needs to be at the left.
+    platform.transpose();
+    platform.mirror_vertical();
+    platform.count_weight_on_northern_support_beams()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Platform::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(platform: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        get_load_after_tilt(platform).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(platform: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        get_load_after_spin_cycles(platform).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day14::solve_first_task(&Day14::parse(reader).unwrap(), &()),
+            Answer::Unsigned(136)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day14::solve_second_task(&Day14::parse(reader).unwrap(), &()),
+            Answer::Unsigned(64)
+        );
+    }
+}
@@ -1,14 +1,5 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Default)]
-enum Task {
-    #[default]
-    First,
-    Second,
-}
+use common::{read_lines, Answer, LineParser, ParseError, Solution};
+use std::io::BufRead;
 
 #[derive(Debug)]
 enum Operation {
@@ -27,10 +18,11 @@ fn calculate_hash(current_hash: usize, c: u8) -> usize {
     ((current_hash + c as usize) * 17) % 256
 }
 
-fn solve_first_task<B: BufRead>(reader: B) -> usize {
+/// Function that sums up the hashes of all steps of the initialization sequence.
+fn sum_of_hashes(sequence: &[u8]) -> usize {
     let mut result = 0;
     let mut hash = 0;
-    for c in reader.bytes().map(Result::unwrap) {
+    for &c in sequence {
         match c {
             b',' => {
                 result += hash;
@@ -48,12 +40,13 @@ fn solve_first_task<B: BufRead>(reader: B) -> usize {
     result
 }
 
-fn solve_second_task<B: BufRead>(reader: B) -> usize {
+/// Function that performs the HASHMAP procedure and returns the focusing power.
+fn get_focusing_power(sequence: &[u8]) -> usize {
     let mut label: String = String::from("");
     let mut hash = 0;
     let mut operation = Operation::Undefined;
     let mut boxes: Vec<Vec<Lens>> = vec![Vec::new(); 256];
-    for c in reader.bytes().map(Result::unwrap) {
+    for &c in sequence {
         match c {
             b',' => {
                 hash = 0;
@@ -93,7 +86,7 @@ fn solve_second_task<B: BufRead>(reader: B) -> usize {
                                 break;
                             }
                         }
-                        if found_lens == false {
+                        if !found_lens {
                             boxes[hash].push(Lens {
                                 label: label.clone(),
                                 focal_length,
@@ -119,42 +112,66 @@ fn solve_second_task<B: BufRead>(reader: B) -> usize {
         .sum()
 }
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
+pub struct Day15;
 
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        match task {
-            Task::First => solve_first_task(reader),
-            Task::Second => solve_second_task(reader),
+impl Solution for Day15 {
+    /// The raw bytes of the initialization sequence.
+    type Input = Vec<u8>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        let mut sequence: Vec<u8> = Vec::new();
+        for (idx, line) in read_lines(reader)?.iter().enumerate() {
+            // Example line: rn=1,cm-,qp=3
+            let mut parser = LineParser::new(line).with_line_number(idx + 1);
+            loop {
+                parser.take_while("a label", |c| c.is_ascii_lowercase())?;
+                if parser.one_of("-=")? == '=' {
+                    parser.one_of("123456789")?;
+                }
+                if !parser.accept(",") {
+                    break;
+                }
+            }
+            parser.end()?;
+            // Every line ends with a newline, the tasks rely on it.
+            sequence.extend(line.bytes());
+            sequence.push(b'\n');
         }
-    )
+        Ok(sequence)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(sequence: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        sum_of_hashes(sequence).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(sequence: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        get_focusing_power(sequence).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader), 1320);
+        assert_eq!(
+            Day15::solve_first_task(&Day15::parse(reader).unwrap(), &()),
+            Answer::Unsigned(1320)
+        );
     }
 
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_second_task(reader), 145);
+        assert_eq!(
+            Day15::solve_second_task(&Day15::parse(reader).unwrap(), &()),
+            Answer::Unsigned(145)
+        );
     }
 }
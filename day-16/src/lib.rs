@@ -0,0 +1,356 @@
+use common::{Answer, ParseError, Solution};
+use graph::{Graph, NodeId};
+use grid::{Direction, Grid, Position};
+use std::io::BufRead;
+
+/// Function that checks if the beam is split by the tile (it hits the flat side of a
+/// splitter).
+fn is_split(tile: char, direction: Direction) -> bool {
+    (tile == '-' && direction.is_vertical()) || (tile == '|' && direction.is_horizontal())
+}
+
+/// Function that follows the beam that enters the cell at the position into the
+/// direction until it is split or leaves the map. Returns the tiles it passes (the
+/// splitter included) and the position of the splitter. Only splitters can make a
+/// beam pass a tile twice into the same direction (mirrors can be undone), so the
+/// beam is also stopped if it comes back to where it started.
+fn trace_beam(
+    map: &Grid<char>,
+    mut position: Position,
+    mut direction: Direction,
+) -> (Vec<Position>, Option<Position>) {
+    let start = (position, direction);
+    let mut tiles: Vec<Position> = Vec::new();
+    loop {
+        tiles.push(position);
+        match map[position] {
+            tile if is_split(tile, direction) => return (tiles, Some(position)),
+            // A beam going right is reflected down, a beam going up to the left.
+            '\\' if direction.is_horizontal() => direction = direction.turn_right(),
+            '\\' => direction = direction.turn_left(),
+            // A beam going right is reflected up, a beam going up to the right.
+            '/' if direction.is_horizontal() => direction = direction.turn_left(),
+            '/' => direction = direction.turn_right(),
+            _ => {}
+        }
+        position = match map.neighbor(position, direction) {
+            Some(next_position) => next_position,
+            None => return (tiles, None),
+        };
+        if (position, direction) == start {
+            return (tiles, None);
+        }
+    }
+}
+
+/// The tiles energized by some beams, one bit per tile.
+#[derive(Debug, Clone)]
+struct TileSet {
+    bits: Vec<u64>,
+}
+
+impl TileSet {
+    fn new(map: &Grid<char>) -> Self {
+        Self {
+            bits: vec![0; (map.width() * map.height()).div_ceil(64)],
+        }
+    }
+
+    fn get_idx(map: &Grid<char>, position: Position) -> usize {
+        position.y * map.width() + position.x
+    }
+
+    fn insert(&mut self, idx: usize) {
+        self.bits[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn contains(&self, idx: usize) -> bool {
+        self.bits[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    fn extend(&mut self, other: &TileSet) {
+        for (bits, other_bits) in self.bits.iter_mut().zip(other.bits.iter()) {
+            *bits |= other_bits;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum()
+    }
+}
+
+/// Function that creates a graph of the splitters: When a beam is split, the two new
+/// beams lead to other splitters (or leave the map). Returns the graph, the node of
+/// every splitter and the tiles the two new beams of every splitter pass.
+fn create_splitter_graph(map: &Grid<char>) -> (Graph, Grid<Option<NodeId>>, Vec<Vec<Position>>) {
+    let mut graph = Graph::new_directed();
+    let mut nodes: Grid<Option<NodeId>> = Grid::new(map.width(), map.height(), None);
+    for position in map
+        .positions()
+        .filter(|&position| "-|".contains(map[position]))
+    {
+        nodes[position] = Some(graph.add_node(&format!("{},{}", position.x, position.y)));
+    }
+    let mut splitter_tiles: Vec<Vec<Position>> = vec![Vec::new(); graph.node_count()];
+    for position in map.positions() {
+        let Some(node) = nodes[position] else {
+            continue;
+        };
+        let directions = match map[position] {
+            '-' => [Direction::Left, Direction::Right],
+            _ => [Direction::Up, Direction::Down],
+        };
+        splitter_tiles[node].push(position);
+        for direction in directions {
+            let Some(next_position) = map.neighbor(position, direction) else {
+                continue;
+            };
+            let (tiles, splitter) = trace_beam(map, next_position, direction);
+            splitter_tiles[node].extend(tiles);
+            if let Some(splitter) = splitter {
+                graph.add_edge(node, nodes[splitter].unwrap());
+            }
+        }
+    }
+    (graph, nodes, splitter_tiles)
+}
+
+/// Function that counts the energized tiles for every entry (a beam that enters the
+/// cell at the position into the direction) in one pass: The splitters that lead to
+/// each other are condensed into strongly connected components, which all energize
+/// the same tiles. The tiles of a component are calculated once from the components
+/// it leads to and dropped as soon as no other component or entry needs them.
+fn count_energized_tiles_of_entries(
+    map: &Grid<char>,
+    entries: &[(Position, Direction)],
+) -> Vec<usize> {
+    let (graph, nodes, splitter_tiles) = create_splitter_graph(map);
+    // Every component comes after the components it leads to.
+    let components = graph.strongly_connected_components();
+    let mut component_of_node: Vec<usize> = vec![0; graph.node_count()];
+    for (idx, component) in components.iter().enumerate() {
+        for &node in component.iter() {
+            component_of_node[node] = idx;
+        }
+    }
+    let mut next_components: Vec<Vec<usize>> = vec![Vec::new(); components.len()];
+    // How often the tiles of a component are still needed.
+    let mut uses: Vec<usize> = vec![0; components.len()];
+    for (idx, component) in components.iter().enumerate() {
+        let mut next: Vec<usize> = component
+            .iter()
+            .flat_map(|&node| graph.neighbors(node))
+            .map(|node| component_of_node[node])
+            .filter(|&next_idx| next_idx != idx)
+            .collect();
+        next.sort();
+        next.dedup();
+        for &next_idx in next.iter() {
+            uses[next_idx] += 1;
+        }
+        next_components[idx] = next;
+    }
+    // The tiles of every entry until the first splitter, grouped by its component.
+    let mut energized_tiles: Vec<usize> = vec![0; entries.len()];
+    let mut entries_of_component: Vec<Vec<(usize, Vec<usize>)>> =
+        vec![Vec::new(); components.len()];
+    for (entry_idx, &(position, direction)) in entries.iter().enumerate() {
+        let (tiles, splitter) = trace_beam(map, position, direction);
+        let mut tiles: Vec<usize> = tiles
+            .into_iter()
+            .map(|position| TileSet::get_idx(map, position))
+            .collect();
+        tiles.sort();
+        tiles.dedup();
+        match splitter {
+            Some(splitter) => {
+                let idx = component_of_node[nodes[splitter].unwrap()];
+                uses[idx] += 1;
+                entries_of_component[idx].push((entry_idx, tiles));
+            }
+            None => energized_tiles[entry_idx] = tiles.len(),
+        }
+    }
+    // A component that no entry reaches is skipped, so it does not need the tiles of
+    // the components it leads to either. Going backwards, every component is checked
+    // after all components that lead to it.
+    for idx in (0..components.len()).rev() {
+        if uses[idx] == 0 {
+            for &next_idx in next_components[idx].iter() {
+                uses[next_idx] -= 1;
+            }
+        }
+    }
+    let mut tile_sets: Vec<Option<TileSet>> = vec![None; components.len()];
+    for (idx, component) in components.iter().enumerate() {
+        if uses[idx] == 0 {
+            continue;
+        }
+        let mut tile_set = TileSet::new(map);
+        for &node in component.iter() {
+            for &position in splitter_tiles[node].iter() {
+                tile_set.insert(TileSet::get_idx(map, position));
+            }
+        }
+        for &next_idx in next_components[idx].iter() {
+            tile_set.extend(tile_sets[next_idx].as_ref().unwrap());
+            uses[next_idx] -= 1;
+            if uses[next_idx] == 0 {
+                tile_sets[next_idx] = None;
+            }
+        }
+        let number_of_tiles = tile_set.len();
+        for (entry_idx, tiles) in entries_of_component[idx].iter() {
+            energized_tiles[*entry_idx] = number_of_tiles
+                + tiles
+                    .iter()
+                    .filter(|&&tile_idx| !tile_set.contains(tile_idx))
+                    .count();
+            uses[idx] -= 1;
+        }
+        if uses[idx] > 0 {
+            tile_sets[idx] = Some(tile_set);
+        }
+    }
+    // Every tile set was dropped as soon as it was not needed anymore.
+    debug_assert!(tile_sets.iter().all(Option::is_none));
+    energized_tiles
+}
+
+/// Function that counts the energized tiles when the beam enters at the top left.
+fn count_energized_tiles(map: &Grid<char>) -> usize {
+    count_energized_tiles_of_entries(map, &[(Position::new(0, 0), Direction::Right)])[0]
+}
+
+/// Function that finds the entry point with the most energized tiles.
+fn count_maximum_energized_tiles(map: &Grid<char>) -> usize {
+    let (width, height) = (map.width(), map.height());
+    let entries: Vec<(Position, Direction)> = (0..height)
+        .flat_map(|y| {
+            [
+                (Position::new(0, y), Direction::Right),
+                (Position::new(width - 1, y), Direction::Left),
+            ]
+        })
+        .chain((0..width).flat_map(|x| {
+            [
+                (Position::new(x, 0), Direction::Down),
+                (Position::new(x, height - 1), Direction::Up),
+            ]
+        }))
+        .collect();
+    count_energized_tiles_of_entries(map, &entries)
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Grid::from_reader(reader, "'.', '/', '\\', '|' or '-'", |c| {
+            "./\\|-".contains(c).then_some(c)
+        })
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(map: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        count_energized_tiles(map).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(map: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        count_maximum_energized_tiles(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day16::solve_first_task(&Day16::parse(reader).unwrap(), &()),
+            Answer::Unsigned(46)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day16::solve_second_task(&Day16::parse(reader).unwrap(), &()),
+            Answer::Unsigned(51)
+        );
+    }
+
+    /// Function that follows the beams tile by tile and marks the directions every
+    /// tile is passed into.
+    fn count_energized_tiles_naive(map: &Grid<char>, entry: (Position, Direction)) -> usize {
+        let mut visited: Grid<Vec<Direction>> = Grid::new(map.width(), map.height(), Vec::new());
+        let mut beams: Vec<(Position, Direction)> = vec![entry];
+        while let Some((position, direction)) = beams.pop() {
+            if visited[position].contains(&direction) {
+                continue;
+            }
+            visited[position].push(direction);
+            let directions = match map[position] {
+                tile if is_split(tile, direction) => {
+                    vec![direction.turn_left(), direction.turn_right()]
+                }
+                '\\' if direction.is_horizontal() => vec![direction.turn_right()],
+                '\\' => vec![direction.turn_left()],
+                '/' if direction.is_horizontal() => vec![direction.turn_left()],
+                '/' => vec![direction.turn_right()],
+                _ => vec![direction],
+            };
+            for direction in directions {
+                if let Some(next_position) = map.neighbor(position, direction) {
+                    beams.push((next_position, direction));
+                }
+            }
+        }
+        visited
+            .positions()
+            .filter(|&position| !visited[position].is_empty())
+            .count()
+    }
+
+    #[test]
+    fn test_all_entries() {
+        // A pseudo random map with many splitters and mirrors, so that the beams run
+        // in circles.
+        let (width, height) = (23, 17);
+        let mut seed: u64 = 16;
+        let mut map: Grid<char> = Grid::new(width, height, '.');
+        for position in map.positions().collect::<Vec<Position>>() {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            map[position] = ['.', '.', '.', '-', '|', '/', '\\'][(seed >> 33) as usize % 7];
+        }
+        let entries: Vec<(Position, Direction)> = map
+            .positions()
+            .flat_map(|position| Direction::ALL.map(|direction| (position, direction)))
+            .collect();
+        let energized_tiles = count_energized_tiles_of_entries(&map, &entries);
+        for (entry, number_of_tiles) in entries.into_iter().zip(energized_tiles) {
+            assert_eq!(
+                number_of_tiles,
+                count_energized_tiles_naive(&map, entry),
+                "{:?}",
+                entry
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Compares the binary heap and the bucket queue of the day 17 path finding on the
+//! puzzle input and on large random maps (always the same ones), e.g.:
+//!
+//! cargo run --release -p day-17 --example benchmark -- day-17/input
+use common::Solution;
+use day_17::{find_route_with, BucketQueue, CrucibleRules, Day17, HeapQueue, PriorityQueue};
+use grid::{Direction, Grid, Position};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    env,
+    fs::File,
+    io::BufReader,
+    time::{Duration, Instant},
+};
+
+/// The number of runs per map, rules and queue. The fastest run is reported.
+const RUNS: usize = 5;
+const RANDOM_MAP_SIZES: [usize; 3] = [250, 500, 1000];
+
+/// Function that finds the route from the top left to the bottom right corner a few
+/// times and returns the heat loss and the fastest time.
+fn measure<Q: PriorityQueue<(Direction, Position)>>(
+    map: &Grid<usize>,
+    rules: CrucibleRules,
+) -> (usize, Duration) {
+    let goal = Position::new(map.width() - 1, map.height() - 1);
+    let mut heat_loss = 0;
+    let mut fastest = Duration::MAX;
+    for _ in 0..RUNS {
+        let start_time = Instant::now();
+        heat_loss = find_route_with::<Q>(map, Position::new(0, 0), goal, rules)
+            .unwrap()
+            .heat_loss;
+        fastest = fastest.min(start_time.elapsed());
+    }
+    (heat_loss, fastest)
+}
+
+fn main() {
+    let input = env::args()
+        .nth(1)
+        .unwrap_or_else(|| String::from("day-17/input"));
+    let reader = BufReader::new(File::open(&input).expect("Input file not found."));
+    let mut maps: Vec<(String, Grid<usize>)> = vec![(input, Day17::parse(reader).unwrap())];
+    let mut rng = StdRng::seed_from_u64(17);
+    for size in RANDOM_MAP_SIZES {
+        let rows: Vec<Vec<usize>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.gen_range(1..=9)).collect())
+            .collect();
+        maps.push((format!("random {}x{}", size, size), Grid::from_rows(rows)));
+    }
+    println!(
+        "{:<20} {:<15} {:>10} {:>12} {:>12}",
+        "map", "rules", "heat loss", "heap", "buckets"
+    );
+    for (name, map) in maps.iter() {
+        for (rules_name, rules) in [
+            ("crucible", CrucibleRules::CRUCIBLE),
+            ("ultra crucible", CrucibleRules::ULTRA_CRUCIBLE),
+        ] {
+            let (heat_loss, heap_time) = measure::<HeapQueue<_>>(map, rules);
+            let (bucket_heat_loss, bucket_time) = measure::<BucketQueue<_>>(map, rules);
+            assert_eq!(heat_loss, bucket_heat_loss, "The queues disagree.");
+            println!(
+                "{:<20} {:<15} {:>10} {:>12.2?} {:>12.2?}",
+                name, rules_name, heat_loss, heap_time, bucket_time
+            );
+        }
+    }
+}
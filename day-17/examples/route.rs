@@ -0,0 +1,55 @@
+//! Prints the route with the least heat loss of a day 17 input on the map, from the
+//! top left to the bottom right corner or between other blocks, e.g.:
+//!
+//! cargo run --release -p day-17 --example route -- day-17/input ultra 0,0 10,20
+use common::Solution;
+use day_17::{find_route, render_route, CrucibleRules, Day17};
+use grid::Position;
+use std::{env, fs::File, io::BufReader, process::exit};
+
+fn parse_position(text: &str) -> Option<Position> {
+    let (x, y) = text.split_once(',')?;
+    Some(Position::new(x.parse().ok()?, y.parse().ok()?))
+}
+
+fn main() {
+    let usage = || -> ! {
+        eprintln!("Usage: route <input> <crucible|ultra> [<start x,y> <goal x,y>]");
+        exit(2);
+    };
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 2 && args.len() != 4 {
+        usage();
+    }
+    let rules = match args[1].as_str() {
+        "crucible" => CrucibleRules::CRUCIBLE,
+        "ultra" => CrucibleRules::ULTRA_CRUCIBLE,
+        _ => usage(),
+    };
+    let reader = BufReader::new(File::open(&args[0]).expect("Input file not found."));
+    let map = Day17::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(args[0].clone()));
+        exit(1);
+    });
+    let (start, goal) = match &args[2..] {
+        [start, goal] => match (parse_position(start), parse_position(goal)) {
+            (Some(start), Some(goal)) => (start, goal),
+            _ => usage(),
+        },
+        _ => (
+            Position::new(0, 0),
+            Position::new(map.width() - 1, map.height() - 1),
+        ),
+    };
+    match find_route(&map, start, goal, rules) {
+        Ok(route) => println!(
+            "{}\nHeat loss: {}",
+            render_route(&map, &route),
+            route.heat_loss
+        ),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            exit(1);
+        }
+    }
+}
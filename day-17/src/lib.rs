@@ -0,0 +1,340 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+use std::{fmt, io::BufRead};
+
+mod queue;
+pub use queue::{BucketQueue, HeapQueue, PriorityQueue};
+
+fn parse_input<B: BufRead>(reader: B) -> Result<Grid<usize>, ParseError> {
+    Grid::from_reader(reader, "a digit", |c| {
+        c.to_digit(10).map(|digit| digit as usize)
+    })
+}
+
+fn direction_to_index(direction: Direction) -> usize {
+    if direction.is_horizontal() {
+        0
+    } else {
+        1
+    }
+}
+
+/// How far a crucible has to move at least and can move at most in a straight line
+/// before it has to turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrucibleRules {
+    pub min_steps: usize,
+    pub max_steps: usize,
+}
+
+impl CrucibleRules {
+    /// The crucible of the first task moves at most three blocks in a straight line.
+    pub const CRUCIBLE: Self = Self {
+        min_steps: 1,
+        max_steps: 3,
+    };
+    /// The ultra crucible of the second task moves at least four and at most ten
+    /// blocks in a straight line.
+    pub const ULTRA_CRUCIBLE: Self = Self {
+        min_steps: 4,
+        max_steps: 10,
+    };
+}
+
+/// The way of a crucible with the least heat loss.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: usize,
+    /// Every block the crucible enters (the start is not included) and the direction
+    /// it moves into the block.
+    pub moves: Vec<(Position, Direction)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteError {
+    /// The start or the goal is not part of the map.
+    OutsideOfMap(Position),
+    /// The crucible has to move more blocks than it can (or can not move at all).
+    InvalidRules(CrucibleRules),
+    /// The crucible can not get from the start to the goal with these rules.
+    Unreachable { start: Position, goal: Position },
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::OutsideOfMap(pos) => {
+                write!(f, "the position {},{} is outside of the map", pos.x, pos.y)
+            }
+            RouteError::InvalidRules(rules) => write!(
+                f,
+                "a crucible can not move at least {} and at most {} blocks",
+                rules.min_steps, rules.max_steps
+            ),
+            RouteError::Unreachable { start, goal } => write!(
+                f,
+                "there is no way from {},{} to {},{}",
+                start.x, start.y, goal.x, goal.y
+            ),
+        }
+    }
+}
+
+/// Function that finds the route from the start to the goal with the least heat loss
+/// (Dijkstra with a bucket queue, see `find_route_with`).
+pub fn find_route(
+    map: &Grid<usize>,
+    start: Position,
+    goal: Position,
+    rules: CrucibleRules,
+) -> Result<Route, RouteError> {
+    find_route_with::<BucketQueue<_>>(map, start, goal, rules)
+}
+
+/// Function that finds the route from the start to the goal with the least heat loss
+/// (Dijkstra with the given priority queue). The crucible can start into any direction
+/// and has to follow the rules until it stops at the goal.
+pub fn find_route_with<Q: PriorityQueue<(Direction, Position)>>(
+    map: &Grid<usize>,
+    start: Position,
+    goal: Position,
+    rules: CrucibleRules,
+) -> Result<Route, RouteError> {
+    for pos in [start, goal] {
+        if !map.contains(pos) {
+            return Err(RouteError::OutsideOfMap(pos));
+        }
+    }
+    // Moving 0 blocks is not moving at all, so it is the same as moving 1 block.
+    let min_steps = rules.min_steps.max(1);
+    if rules.max_steps == 0 || min_steps > rules.max_steps {
+        return Err(RouteError::InvalidRules(rules));
+    }
+    // A distance-map that keeps track of the smallest distances we found.
+    let mut distances: Grid<[usize; 2]> = Grid::new(map.width(), map.height(), [usize::MAX; 2]);
+    distances[start] = [0, 0];
+    // For every block and direction the block where we turned the last time and the
+    // direction we moved into since then, so that we can find the way back.
+    let mut parents: Grid<[Option<(Position, Direction)>; 2]> =
+        Grid::new(map.width(), map.height(), [None; 2]);
+    // We also need to keep track of the next nodes to consider. The next node should
+    // always be the node with the smallest distance.
+    let mut queue = Q::new();
+    queue.push(0, (Direction::Right, start));
+    queue.push(0, (Direction::Down, start));
+    // While there is something in the queue, calculate new distances.
+    // Stop if we arrived at the goal.
+    while let Some((distance, (direction, pos))) = queue.pop() {
+        // If we see the goal we can be sure that this is the fastest way to get there
+        // because it was the first in the queue.
+        if pos == goal {
+            return Ok(Route {
+                heat_loss: distance,
+                moves: collect_moves(&parents, pos, direction),
+            });
+        }
+        // Check if we already found a better way to this spot.
+        if distance > distances[pos][direction_to_index(direction)] {
+            continue;
+        }
+        // Now we move in the two possible directions as far as possible.
+        // We will not move in the same direction we came from because we already
+        // moved there as far as we could.
+        for new_direction in [direction.turn_left(), direction.turn_right()] {
+            // We move into one direction and add up the cost to get here.
+            let mut acc_distance = distance;
+            let mut new_pos = pos;
+            for steps in 1..=rules.max_steps {
+                // Still inside the map?
+                new_pos = match map.neighbor(new_pos, new_direction) {
+                    Some(new_pos) => new_pos,
+                    None => break,
+                };
+                acc_distance += map[new_pos];
+                // Have we done enough steps already?
+                if steps >= min_steps {
+                    // Check if we are better than the previous one.
+                    // Also keep track of the direction we came from.
+                    let idx = direction_to_index(new_direction);
+                    if acc_distance < distances[new_pos][idx] {
+                        // We found a better solution.
+                        distances[new_pos][idx] = acc_distance;
+                        parents[new_pos][idx] = Some((pos, new_direction));
+                        queue.push(acc_distance, (new_direction, new_pos));
+                    }
+                }
+            }
+        }
+    }
+    Err(RouteError::Unreachable { start, goal })
+}
+
+/// Function that follows the parents back from the block (reached by moving into the
+/// direction) to the start and returns the moves from the start to the block.
+fn collect_moves(
+    parents: &Grid<[Option<(Position, Direction)>; 2]>,
+    mut pos: Position,
+    mut direction: Direction,
+) -> Vec<(Position, Direction)> {
+    let mut moves: Vec<(Position, Direction)> = Vec::new();
+    while let Some((previous_pos, moved_direction)) = parents[pos][direction_to_index(direction)] {
+        // Walk back in a straight line to where we turned the last time.
+        while pos != previous_pos {
+            moves.push((pos, moved_direction));
+            pos = pos.step(moved_direction.reverse()).unwrap();
+        }
+        // Here we came from the other axis.
+        direction = moved_direction.turn_left();
+    }
+    moves.reverse();
+    moves
+}
+
+/// Function that shows the route on the map like in the puzzle text: Every block the
+/// crucible enters shows the direction it moves into it ('>', '<', '^' or 'v').
+pub fn render_route(map: &Grid<usize>, route: &Route) -> String {
+    let mut text_map: Grid<char> =
+        map.map(|&heat_loss| char::from_digit(heat_loss as u32, 10).unwrap());
+    for &(pos, direction) in route.moves.iter() {
+        text_map[pos] = direction.to_arrow();
+    }
+    text_map.render(|&c| c)
+}
+
+/// Function that finds the least heat loss from the top left to the bottom right
+/// corner (or the reason why there is no route).
+fn find_least_heat_loss(map: &Grid<usize>, rules: CrucibleRules) -> Answer {
+    let goal = Position::new(map.width() - 1, map.height() - 1);
+    match find_route(map, Position::new(0, 0), goal, rules) {
+        Ok(route) => route.heat_loss.into(),
+        Err(error) => Answer::Unsolved(error.to_string()),
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_input(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(map: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        find_least_heat_loss(map, CrucibleRules::CRUCIBLE)
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(map: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        find_least_heat_loss(map, CrucibleRules::ULTRA_CRUCIBLE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, Cursor},
+    };
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day17::solve_first_task(&Day17::parse(reader).unwrap(), &()),
+            Answer::Unsigned(102)
+        );
+    }
+
+    #[test]
+    fn test_find_route() {
+        check_find_route::<HeapQueue<_>>();
+        check_find_route::<BucketQueue<_>>();
+    }
+
+    fn check_find_route<Q: PriorityQueue<(Direction, Position)>>() {
+        let find_route = find_route_with::<Q>;
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        let map = Day17::parse(reader).unwrap();
+        let goal = Position::new(12, 12);
+        let route = find_route(&map, Position::new(0, 0), goal, CrucibleRules::CRUCIBLE).unwrap();
+        assert_eq!(route.heat_loss, 102);
+        assert_eq!(
+            route.moves.iter().map(|&(pos, _)| map[pos]).sum::<usize>(),
+            102
+        );
+        // The same route as in the puzzle text.
+        assert_eq!(
+            render_route(&map, &route),
+            [
+                "2>>34^>>>1323",
+                "32v>>>35v5623",
+                "32552456v>>54",
+                "3446585845v52",
+                "4546657867v>6",
+                "14385987984v4",
+                "44578769877v6",
+                "36378779796v>",
+                "465496798688v",
+                "456467998645v",
+                "12246868655<v",
+                "25465488877v5",
+                "43226746555v>",
+            ]
+            .join("\n")
+        );
+        assert_eq!(
+            find_route(&map, goal, goal, CrucibleRules::CRUCIBLE),
+            Ok(Route {
+                heat_loss: 0,
+                moves: vec![]
+            })
+        );
+        let rules = CrucibleRules {
+            min_steps: 13,
+            max_steps: 20,
+        };
+        assert_eq!(
+            find_route(&map, Position::new(0, 0), goal, rules),
+            Err(RouteError::Unreachable {
+                start: Position::new(0, 0),
+                goal
+            })
+        );
+        let rules = CrucibleRules {
+            min_steps: 4,
+            max_steps: 3,
+        };
+        assert_eq!(
+            find_route(&map, Position::new(0, 0), goal, rules),
+            Err(RouteError::InvalidRules(rules))
+        );
+        assert_eq!(
+            find_route(&map, Position::new(0, 13), goal, CrucibleRules::CRUCIBLE),
+            Err(RouteError::OutsideOfMap(Position::new(0, 13)))
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day17::solve_second_task(&Day17::parse(reader).unwrap(), &()),
+            Answer::Unsigned(94)
+        );
+    }
+
+    #[test]
+    fn test_no_route() {
+        // The ultra crucible has to move at least four blocks before it can stop.
+        let map = Day17::parse(Cursor::new("123")).unwrap();
+        assert_eq!(Day17::solve_first_task(&map, &()), Answer::Unsigned(5));
+        assert_eq!(
+            Day17::solve_second_task(&map, &()),
+            Answer::Unsolved(String::from("there is no way from 0,0 to 2,0"))
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// A queue that always returns the item with the smallest distance first.
+pub trait PriorityQueue<T> {
+    fn new() -> Self;
+    fn push(&mut self, distance: usize, item: T);
+    fn pop(&mut self) -> Option<(usize, T)>;
+}
+
+/// Priority queue that is a binary heap. This is a tree where the root is always the
+/// largest element. We want the root to be the smallest distance, so we reverse the
+/// ordering.
+pub struct HeapQueue<T> {
+    heap: BinaryHeap<(Reverse<usize>, T)>,
+}
+
+impl<T: Ord> PriorityQueue<T> for HeapQueue<T> {
+    fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn push(&mut self, distance: usize, item: T) {
+        self.heap.push((Reverse(distance), item));
+    }
+
+    fn pop(&mut self) -> Option<(usize, T)> {
+        self.heap
+            .pop()
+            .map(|(Reverse(distance), item)| (distance, item))
+    }
+}
+
+/// Priority queue with one bucket per distance (Dial's algorithm). This works because
+/// Dijkstra never pushes a distance that is smaller than the last one popped, so we
+/// only have to look at the buckets from there on. No item is compared to another.
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    /// The distance of the bucket to look at first.
+    current: usize,
+    len: usize,
+}
+
+impl<T> PriorityQueue<T> for BucketQueue<T> {
+    fn new() -> Self {
+        Self {
+            buckets: Vec::new(),
+            current: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, distance: usize, item: T) {
+        assert!(
+            distance >= self.current,
+            "A bucket queue can not go back to smaller distances."
+        );
+        if distance >= self.buckets.len() {
+            self.buckets.resize_with(distance + 1, Vec::new);
+        }
+        self.buckets[distance].push(item);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<(usize, T)> {
+        if self.len == 0 {
+            return None;
+        }
+        while self.buckets[self.current].is_empty() {
+            self.current += 1;
+        }
+        self.len -= 1;
+        let item = self.buckets[self.current].pop().unwrap();
+        Some((self.current, item))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_queue<Q: PriorityQueue<char>>() {
+        let mut queue = Q::new();
+        queue.push(5, 'a');
+        queue.push(2, 'b');
+        queue.push(9, 'c');
+        assert_eq!(queue.pop(), Some((2, 'b')));
+        queue.push(3, 'd');
+        assert_eq!(queue.pop(), Some((3, 'd')));
+        assert_eq!(queue.pop(), Some((5, 'a')));
+        assert_eq!(queue.pop(), Some((9, 'c')));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn test_queues() {
+        check_queue::<HeapQueue<char>>();
+        check_queue::<BucketQueue<char>>();
+    }
+}
@@ -1,25 +1,16 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-    iter::FromIterator,
-};
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use grid::{Direction, Point};
+use std::{collections::HashMap, io::BufRead, iter::FromIterator};
 
 #[derive(Debug, Default, Copy, Clone)]
-enum Task {
+pub enum Task {
     #[default]
     First,
     Second,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Direction {
-    Left,
-    Right,
-    Up,
-    Down,
-    Unknown,
-}
+/// One step of the dig plan: the direction and the number of meters.
+type Instruction = (Direction, isize);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum EdgeType {
@@ -42,7 +33,13 @@ struct Trench {
     map: HashMap<isize, Vec<TrenchEdge>>,
 }
 
-fn get_edge_type(direction1: Direction, direction2: Direction) -> EdgeType {
+/// Function that determines the corner between two directions. The first direction
+/// is unknown at the start of the dig plan.
+fn get_edge_type(direction1: Option<Direction>, direction2: Direction) -> EdgeType {
+    let direction1 = match direction1 {
+        Some(direction1) => direction1,
+        None => return EdgeType::Unknown,
+    };
     match (direction1, direction2) {
         (Direction::Left, Direction::Up) | (Direction::Down, Direction::Right) => {
             EdgeType::CornerRightUp
@@ -56,7 +53,6 @@ fn get_edge_type(direction1: Direction, direction2: Direction) -> EdgeType {
         (Direction::Right, Direction::Down) | (Direction::Up, Direction::Left) => {
             EdgeType::CornerLeftDown
         }
-        (Direction::Unknown, _) => EdgeType::Unknown,
         _ => unreachable!(),
     }
 }
@@ -68,64 +64,54 @@ impl FromIterator<(Direction, isize)> for Trench {
     {
         // Our result we build from the iterator.
         let mut map: HashMap<isize, Vec<TrenchEdge>> = HashMap::new();
-        let mut x: isize = 0;
-        let mut y: isize = 0;
-        let mut prev_direction = Direction::Unknown;
+        let mut point = Point::default();
+        let mut prev_direction: Option<Direction> = None;
         // At the end we need to set the first direction.
-        let mut first_direction = Direction::Unknown;
-        let mut idx_instruction = 0;
+        let mut first_direction: Option<Direction> = None;
 
         for (direction, steps) in iter {
-            // Save the first direction and color for later use.
-            if idx_instruction == 0 {
-                first_direction = direction;
+            // Save the first direction for later use.
+            if first_direction.is_none() {
+                first_direction = Some(direction);
             }
-            idx_instruction += 1;
             // Determine what kind of corner we have here.
             let edge_type = get_edge_type(prev_direction, direction);
             // Set the corner at the current position but only if we know the type.
             if edge_type != EdgeType::Unknown {
-                map.entry(y)
-                    .or_insert_with(Vec::new)
-                    .push(TrenchEdge { x, edge_type });
+                map.entry(point.y).or_default().push(TrenchEdge {
+                    x: point.x,
+                    edge_type,
+                });
             }
             // If we are moving up or down we will also need the vertical edges.
-            if direction == Direction::Up || direction == Direction::Down {
+            if direction.is_vertical() {
                 for _ in 0..steps - 1 {
-                    if direction == Direction::Up {
-                        y -= 1;
-                    } else {
-                        y += 1;
-                    }
-                    map.entry(y).or_insert_with(Vec::new).push(TrenchEdge {
-                        x,
+                    point = point.step(direction);
+                    map.entry(point.y).or_default().push(TrenchEdge {
+                        x: point.x,
                         edge_type: EdgeType::Vertical,
                     });
                 }
                 // Last step that is not going to be inserted.
-                if direction == Direction::Up {
-                    y -= 1;
-                } else {
-                    y += 1;
-                }
-            } else if direction == Direction::Left {
-                x -= steps;
+                point = point.step(direction);
             } else {
-                x += steps;
+                point = point.moved(direction, steps);
             }
 
             // Next one.
-            prev_direction = direction;
+            prev_direction = Some(direction);
         }
         // Get the first corner for position (0, 0).
-        let edge_type = get_edge_type(prev_direction, first_direction);
-        map.entry(0)
-            .or_insert_with(Vec::new)
-            .push(TrenchEdge { x: 0, edge_type });
+        if let Some(first_direction) = first_direction {
+            let edge_type = get_edge_type(prev_direction, first_direction);
+            map.entry(0)
+                .or_default()
+                .push(TrenchEdge { x: 0, edge_type });
+        }
 
         // Sort the map.
         for (_, edges) in map.iter_mut() {
-            edges.sort_by(|a, b| a.x.cmp(&b.x));
+            edges.sort_by_key(|a| a.x);
         }
 
         Trench { map }
@@ -186,134 +172,172 @@ impl Trench {
     }
 }
 
-fn parse_line(line: String, task: Task) -> (Direction, isize) {
+/// Function that parses a line for both tasks. The first task uses the direction and
+/// the number of steps, the second task decodes the color instead.
+fn parse_line(line: String) -> Result<(Instruction, Instruction), ParseError> {
     // Example line: R 6 (#70c710)
-    let mut parts = line.split_ascii_whitespace();
-    match task {
-        Task::First => {
-            let direction = match parts.next().unwrap() {
-                "L" => Direction::Left,
-                "R" => Direction::Right,
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                _ => unreachable!("Unknown direction found in parse_line."),
-            };
-            let steps = parts.next().unwrap().parse::<isize>().unwrap();
-            return (direction, steps);
+    let mut parser = LineParser::new(&line);
+    let direction = Direction::from_char(parser.one_of("LRUD")?).unwrap();
+    let steps = parser.number::<isize>()?;
+    parser.expect(" (#")?;
+    let color_parser = parser.clone();
+    let color_string = parser.take_while("a hexadecimal color", |c| c.is_ascii_hexdigit())?;
+    if color_string.len() != 6 {
+        return Err(color_parser
+            .error("a hexadecimal color with 6 digits")
+            .found(format!("'{}'", color_string)));
+    }
+    parser.expect(")")?;
+    parser.end()?;
+    let color_steps = isize::from_str_radix(&color_string[..5], 16).unwrap();
+    // "0 means R, 1 means D, 2 means L, and 3 means U"
+    let color_direction = match &color_string[5..6] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => {
+            return Err(color_parser
+                .error("a color that ends with 0, 1, 2 or 3")
+                .found(format!("'{}'", color_string)))
         }
-        Task::Second => {
-            let color_string = parts.nth(2).unwrap();
-            let steps = isize::from_str_radix(&color_string[2..7], 16).unwrap();
-            // "0 means R, 1 means D, 2 means L, and 3 means U"
-            let direction = match &color_string[7..8] {
-                "0" => Direction::Right,
-                "1" => Direction::Down,
-                "2" => Direction::Left,
-                "3" => Direction::Up,
-                _ => unreachable!(),
-            };
-            return (direction, steps);
+    };
+    Ok(((direction, steps), (color_direction, color_steps)))
+}
+
+/// The dig plan, read once for each way of interpreting a line.
+pub struct DigPlan {
+    instructions_first_task: Vec<Instruction>,
+    instructions_second_task: Vec<Instruction>,
+}
+
+impl DigPlan {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        let (instructions_first_task, instructions_second_task) =
+            parse_lines(reader, parse_line)?.into_iter().unzip();
+        Ok(Self {
+            instructions_first_task,
+            instructions_second_task,
+        })
+    }
+
+    fn get_instructions(&self, task: Task) -> &[Instruction] {
+        match task {
+            Task::First => &self.instructions_first_task,
+            Task::Second => &self.instructions_second_task,
         }
     }
 }
 
-fn solve_task_raycast<B: BufRead>(reader: B, task: Task) -> usize {
-    let trench: Trench = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| parse_line(line, task))
-        .collect();
+pub fn solve_task_raycast(dig_plan: &DigPlan, task: Task) -> usize {
+    let trench: Trench = dig_plan.get_instructions(task).iter().cloned().collect();
     trench.count_cubic_meters() as usize
 }
 
-fn solve_task_shoelace<B: BufRead>(reader: B, task: Task) -> usize {
+pub fn solve_task_shoelace(dig_plan: &DigPlan, task: Task) -> usize {
     let mut area = 0;
-    let instructions: Vec<(Direction, isize)> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(|line| parse_line(line, task))
-        .collect();
-    let (mut x, mut y): (isize, isize) = (0, 0);
+    let instructions = dig_plan.get_instructions(task);
+    let mut point = Point::default();
     let mut perimeter = 0;
-    for (idx, (direction, steps)) in instructions.iter().enumerate() {
+    for (idx, &(direction, steps)) in instructions.iter().enumerate() {
         perimeter += steps;
-        let (mut x_new, mut y_new) = (x, y);
-        match direction {
-            Direction::Left => x_new -= steps,
-            Direction::Right => x_new += steps,
-            Direction::Up => y_new -= steps,
-            Direction::Down => y_new += steps,
-            _ => unreachable!(),
-        }
+        let point_new = point.moved(direction, steps);
         if idx < instructions.len() - 1 {
-            area += x * y_new - x_new * y;
+            area += point.x * point_new.y - point_new.x * point.y;
         }
-        x = x_new;
-        y = y_new;
+        point = point_new;
     }
-    let result = (isize::abs(area) / 2) as usize + (perimeter / 2) as usize + 1;
-    result
+
+    (isize::abs(area) / 2) as usize + (perimeter / 2) as usize + 1
 }
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
-
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        solve_task_shoelace(reader, task)
-    )
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        DigPlan::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(dig_plan: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task_shoelace(dig_plan, Task::First).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(dig_plan: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        solve_task_shoelace(dig_plan, Task::Second).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_first_task_raycast() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task_raycast(reader, Task::First), 62);
+        assert_eq!(
+            solve_task_raycast(&Day18::parse(reader).unwrap(), Task::First),
+            62
+        );
     }
 
     #[test]
     fn test_first_task_shoelace() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task_shoelace(reader, Task::First), 62);
+        assert_eq!(
+            solve_task_shoelace(&Day18::parse(reader).unwrap(), Task::First),
+            62
+        );
     }
 
     #[test]
     fn test_first_task_large_raycast() {
         let reader =
             BufReader::new(File::open("./input_large.test").expect("Input file not found."));
-        assert_eq!(solve_task_raycast(reader, Task::First), 39194);
+        assert_eq!(
+            solve_task_raycast(&Day18::parse(reader).unwrap(), Task::First),
+            39194
+        );
     }
 
     #[test]
     fn test_first_task_large_shoelace() {
         let reader =
             BufReader::new(File::open("./input_large.test").expect("Input file not found."));
-        assert_eq!(solve_task_shoelace(reader, Task::First), 39194);
+        assert_eq!(
+            solve_task_shoelace(&Day18::parse(reader).unwrap(), Task::First),
+            39194
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_line(String::from("R 6 (#70c71)")).unwrap_err(),
+            ParseError::new(1, 7, "a hexadecimal color with 6 digits").found("'70c71'")
+        );
     }
 
     #[test]
     fn test_second_task_raycast() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task_raycast(reader, Task::Second), 952408144115);
+        assert_eq!(
+            solve_task_raycast(&Day18::parse(reader).unwrap(), Task::Second),
+            952408144115
+        );
     }
 
     #[test]
     fn test_second_task_shoelace() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_task_shoelace(reader, Task::Second), 952408144115);
+        assert_eq!(
+            solve_task_shoelace(&Day18::parse(reader).unwrap(), Task::Second),
+            952408144115
+        );
     }
 }
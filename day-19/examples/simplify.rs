@@ -0,0 +1,48 @@
+//! Reports the dead rules, the unreachable workflows and the workflows that always
+//! give the same result of a day 19 input and prints a simplified input that gives the
+//! same answers, e.g.:
+//!
+//! cargo run --release -p day-19 --example simplify -- day-19/input > simplified
+use common::Solution;
+use day_19::{Day19, RATING_BOUNDS};
+use std::{env, fs::File, io::BufReader, process::exit};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [input] = &args[..] else {
+        eprintln!("Usage: simplify <input>");
+        exit(2);
+    };
+    let reader = BufReader::new(File::open(input).expect("Input file not found."));
+    let (workflow_collection, _, parts) = Day19::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(input.clone()));
+        exit(1);
+    });
+    // The workflows were compiled while parsing, so they can be analyzed.
+    let analysis = workflow_collection.analyze(&RATING_BOUNDS).unwrap();
+    for (name, idx) in analysis.dead_rules.iter() {
+        let comparison = &workflow_collection.workflows[name].comparisons[*idx];
+        eprintln!("Dead rule: {} ({})", name, comparison);
+    }
+    for (name, idx) in analysis.always_firing_rules.iter() {
+        let comparison = &workflow_collection.workflows[name].comparisons[*idx];
+        eprintln!("Always firing rule: {} ({})", name, comparison);
+    }
+    for name in analysis.unreachable_workflows.iter() {
+        eprintln!("Unreachable workflow: {}", name);
+    }
+    for (name, result) in analysis.constant_workflows.iter() {
+        eprintln!("Constant workflow: {} (always {})", name, result);
+    }
+    let simplified = workflow_collection.simplify(&RATING_BOUNDS).unwrap();
+    eprintln!(
+        "{} of {} workflows left.",
+        simplified.workflows.len(),
+        workflow_collection.workflows.len()
+    );
+    print!("{}", simplified);
+    println!();
+    for part in parts.iter() {
+        println!("{}", part);
+    }
+}
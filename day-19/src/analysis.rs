@@ -0,0 +1,259 @@
+use crate::{
+    decision_tree::{CompileError, DecisionTree},
+    workflow::{PartRanges, Workflow, WorkflowCollection, WorkflowResult},
+};
+use std::collections::{HashMap, HashSet};
+
+/// What the analysis of the workflows found out for the parts within the bounds. All
+/// lists are sorted by the name of the workflow (and the index of the rule).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// The rules that no part passes (name of the workflow and index of the
+    /// comparison). All rules of the unreachable workflows are left out.
+    pub dead_rules: Vec<(String, usize)>,
+    /// The rules that every part reaching them passes, so the rules after them and
+    /// the last rule of the workflow are never used.
+    pub always_firing_rules: Vec<(String, usize)>,
+    /// The workflows that no part is sent to, starting at "in".
+    pub unreachable_workflows: Vec<String>,
+    /// The workflows that accept all parts reaching them or reject all of them.
+    pub constant_workflows: Vec<(String, WorkflowResult)>,
+}
+
+/// Function that replaces the name of a workflow by what it was replaced with (this
+/// can be another workflow that was replaced as well).
+fn resolve(result: &mut WorkflowResult, replacements: &HashMap<String, WorkflowResult>) {
+    loop {
+        let replacement = match &*result {
+            WorkflowResult::NextWorkflow(name) => replacements.get(name),
+            _ => None,
+        };
+        match replacement {
+            Some(replacement) => *result = replacement.clone(),
+            None => return,
+        }
+    }
+}
+
+impl WorkflowCollection {
+    /// Function that sends the boxes of ratings within the bounds through the
+    /// workflows (starting at "in") and checks which rules and workflows are used.
+    /// Fails if the workflows can not be compiled.
+    pub fn analyze(&self, bounds: &PartRanges) -> Result<Analysis, CompileError> {
+        let decision_tree = DecisionTree::compile(self)?;
+        // Per workflow the number of combinations reaching it and the accepted ones.
+        let mut reached: HashMap<&str, (usize, usize)> = HashMap::new();
+        // Per workflow and rule the number of combinations passing and failing it.
+        let mut rule_counts: HashMap<&str, Vec<(usize, usize)>> = HashMap::new();
+        let mut todo: Vec<(&str, PartRanges)> = vec![("in", *bounds)];
+        while let Some((name, part_ranges)) = todo.pop() {
+            let workflow = &self.workflows[name];
+            let accepted: usize = decision_tree
+                .accepted_boxes_of_workflow(name, &part_ranges)
+                .unwrap()
+                .iter()
+                .map(PartRanges::volume)
+                .sum();
+            let counts = reached.entry(name).or_insert((0, 0));
+            counts.0 += part_ranges.volume();
+            counts.1 += accepted;
+            let rule_counts = rule_counts
+                .entry(name)
+                .or_insert_with(|| vec![(0, 0); workflow.comparisons.len()]);
+            let mut rest = Some(part_ranges);
+            for (idx, comparison) in workflow.comparisons.iter().enumerate() {
+                let Some(current) = rest else {
+                    break;
+                };
+                let (passed, failed) =
+                    comparison
+                        .operator
+                        .split(&current, comparison.category, comparison.value);
+                rule_counts[idx].0 += passed.map_or(0, |passed| passed.volume());
+                rule_counts[idx].1 += failed.map_or(0, |failed| failed.volume());
+                if let (Some(passed), WorkflowResult::NextWorkflow(next)) =
+                    (passed, &comparison.result)
+                {
+                    todo.push((next, passed));
+                }
+                rest = failed;
+            }
+            if let (Some(rest), WorkflowResult::NextWorkflow(next)) = (rest, &workflow.otherwise) {
+                todo.push((next, rest));
+            }
+        }
+        let mut names: Vec<&String> = self.workflows.keys().collect();
+        names.sort();
+        let mut analysis = Analysis {
+            dead_rules: Vec::new(),
+            always_firing_rules: Vec::new(),
+            unreachable_workflows: Vec::new(),
+            constant_workflows: Vec::new(),
+        };
+        for name in names {
+            let Some(&(number_reached, number_accepted)) = reached.get(name.as_str()) else {
+                analysis.unreachable_workflows.push(name.clone());
+                continue;
+            };
+            for (idx, &(passed, failed)) in rule_counts[name.as_str()].iter().enumerate() {
+                if passed == 0 {
+                    analysis.dead_rules.push((name.clone(), idx));
+                } else if failed == 0 {
+                    analysis.always_firing_rules.push((name.clone(), idx));
+                }
+            }
+            if number_accepted == 0 {
+                analysis
+                    .constant_workflows
+                    .push((name.clone(), WorkflowResult::Reject));
+            } else if number_accepted == number_reached {
+                analysis
+                    .constant_workflows
+                    .push((name.clone(), WorkflowResult::Accept));
+            }
+        }
+        Ok(analysis)
+    }
+
+    /// Function that creates workflows that give the same result for all parts within
+    /// the bounds: The unreachable workflows and the dead rules are removed, the rules
+    /// after an always firing rule as well, and the workflows that always give the
+    /// same result (or only send the parts on to another workflow) are inlined.
+    pub fn simplify(&self, bounds: &PartRanges) -> Result<WorkflowCollection, CompileError> {
+        let analysis = self.analyze(bounds)?;
+        let dead_rules: HashSet<&(String, usize)> = analysis.dead_rules.iter().collect();
+        let always_firing_rules: HashSet<&(String, usize)> =
+            analysis.always_firing_rules.iter().collect();
+        let constant_workflows: HashMap<&String, &WorkflowResult> = analysis
+            .constant_workflows
+            .iter()
+            .map(|(name, result)| (name, result))
+            .collect();
+        let mut workflows: Vec<Workflow> = Vec::new();
+        for (name, workflow) in self.workflows.iter() {
+            if analysis.unreachable_workflows.contains(name) {
+                continue;
+            }
+            let mut simplified_workflow = Workflow {
+                name: name.clone(),
+                comparisons: Vec::new(),
+                otherwise: workflow.otherwise.clone(),
+            };
+            if let Some(&result) = constant_workflows.get(name) {
+                simplified_workflow.otherwise = result.clone();
+                workflows.push(simplified_workflow);
+                continue;
+            }
+            for (idx, comparison) in workflow.comparisons.iter().enumerate() {
+                let rule = (name.clone(), idx);
+                if dead_rules.contains(&rule) {
+                    continue;
+                }
+                if always_firing_rules.contains(&rule) {
+                    simplified_workflow.otherwise = comparison.result.clone();
+                    break;
+                }
+                simplified_workflow.comparisons.push(comparison.clone());
+            }
+            workflows.push(simplified_workflow);
+        }
+        // Inlining can make the last rules of other workflows superfluous (same result
+        // as the last rule), so that they can be inlined as well.
+        let mut replacements: HashMap<String, WorkflowResult> = HashMap::new();
+        loop {
+            let mut changed = false;
+            for workflow in workflows.iter_mut() {
+                for comparison in workflow.comparisons.iter_mut() {
+                    resolve(&mut comparison.result, &replacements);
+                }
+                resolve(&mut workflow.otherwise, &replacements);
+                while workflow
+                    .comparisons
+                    .last()
+                    .is_some_and(|comparison| comparison.result == workflow.otherwise)
+                {
+                    workflow.comparisons.pop();
+                }
+                if workflow.name != "in"
+                    && workflow.comparisons.is_empty()
+                    && !replacements.contains_key(&workflow.name)
+                {
+                    replacements.insert(workflow.name.clone(), workflow.otherwise.clone());
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        Ok(WorkflowCollection {
+            workflows: workflows
+                .into_iter()
+                .filter(|workflow| !replacements.contains_key(&workflow.name))
+                .map(|workflow| (workflow.name.clone(), workflow))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::RATING_BOUNDS;
+
+    fn parse(lines: &[&str]) -> WorkflowCollection {
+        let mut workflow_collection = WorkflowCollection::new();
+        for line in lines {
+            let workflow = Workflow::from_line(line.to_string()).unwrap();
+            workflow_collection
+                .workflows
+                .insert(workflow.name.clone(), workflow);
+        }
+        workflow_collection
+    }
+
+    fn example() -> WorkflowCollection {
+        parse(&[
+            "in{x<100:ab,x<50:R,cd}",
+            "ab{m>10:A,ef}",
+            "cd{s>10:R,R}",
+            "ef{a<5:gh,A}",
+            "gh{x>200:R,A}",
+            "xy{A}",
+        ])
+    }
+
+    #[test]
+    fn test_analyze() {
+        let analysis = example().analyze(&RATING_BOUNDS).unwrap();
+        assert_eq!(
+            analysis,
+            Analysis {
+                // "x<50" comes after "x<100", "x>200" only gets x below 100.
+                dead_rules: vec![(String::from("gh"), 0), (String::from("in"), 1)],
+                always_firing_rules: vec![],
+                unreachable_workflows: vec![String::from("xy")],
+                constant_workflows: vec![
+                    (String::from("ab"), WorkflowResult::Accept),
+                    (String::from("cd"), WorkflowResult::Reject),
+                    (String::from("ef"), WorkflowResult::Accept),
+                    (String::from("gh"), WorkflowResult::Accept),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_simplify() {
+        let simplified = example().simplify(&RATING_BOUNDS).unwrap();
+        assert_eq!(simplified.to_string(), "in{x<100:A,R}\n");
+        // Only parts with x below 10 reach "gh", so "x<20" always fires and "gh" only
+        // sends them on to "ab".
+        let workflow_collection = parse(&["in{x<10:gh,R}", "gh{x<20:ab,m>5:R,A}", "ab{s>5:A,R}"]);
+        let analysis = workflow_collection.analyze(&RATING_BOUNDS).unwrap();
+        assert_eq!(analysis.always_firing_rules, vec![(String::from("gh"), 0)]);
+        assert_eq!(analysis.dead_rules, vec![(String::from("gh"), 1)]);
+        let simplified = workflow_collection.simplify(&RATING_BOUNDS).unwrap();
+        assert_eq!(simplified.to_string(), "in{x<10:ab,R}\nab{s>5:A,R}\n");
+    }
+}
@@ -0,0 +1,350 @@
+use crate::workflow::{Category, Operator, Part, PartRanges, WorkflowCollection, WorkflowResult};
+use common::ParseError;
+use graph::Interner;
+
+/// A node of the decision tree: Either the part is accepted or rejected, or one
+/// rating is tested and the next node depends on the outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Node {
+    Accept,
+    Reject,
+    Test {
+        category: Category,
+        operator: Operator,
+        value: usize,
+        then: usize,
+        otherwise: usize,
+    },
+}
+
+const ACCEPT: usize = 0;
+const REJECT: usize = 1;
+
+/// Problems of the workflows that are found while compiling them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompileError {
+    /// There is no workflow named "in".
+    MissingStart,
+    /// The workflow sends parts to a workflow that does not exist.
+    UndefinedWorkflow { workflow: String, name: String },
+    /// The workflows send parts around in a circle (the first workflow is repeated at
+    /// the end).
+    Cycle(Vec<String>),
+}
+
+impl CompileError {
+    /// Function that returns the name of the workflow the problem was found in.
+    pub fn workflow(&self) -> Option<&str> {
+        match self {
+            CompileError::MissingStart => None,
+            CompileError::UndefinedWorkflow { workflow, .. } => Some(workflow),
+            CompileError::Cycle(workflows) => Some(&workflows[0]),
+        }
+    }
+
+    /// Function that turns the problem into a parse error in the given line.
+    pub fn into_parse_error(self, line: usize) -> ParseError {
+        match self {
+            CompileError::MissingStart => {
+                ParseError::new(line, 1, "a workflow named 'in'").found("none")
+            }
+            CompileError::UndefinedWorkflow { name, .. } => {
+                ParseError::new(line, 1, "only names of existing workflows")
+                    .found(format!("'{}'", name))
+            }
+            CompileError::Cycle(workflows) => ParseError::new(line, 1, "workflows without a cycle")
+                .found(format!("'{}'", workflows.join(" -> "))),
+        }
+    }
+}
+
+/// The workflows compiled into one tree of tests (a node can be reached in several
+/// ways, because several rules can send parts to the same workflow). The names of
+/// the workflows are replaced by the index of their first node.
+#[derive(Debug)]
+pub struct DecisionTree {
+    pub nodes: Vec<Node>,
+    pub names: Interner,
+    /// The first node of every workflow (by the number of its name).
+    pub entries: Vec<usize>,
+}
+
+impl DecisionTree {
+    /// Function that compiles the workflows. Fails if a workflow sends parts to a
+    /// workflow that does not exist, if parts can be sent around in a circle or if
+    /// there is no workflow "in".
+    pub fn compile(workflow_collection: &WorkflowCollection) -> Result<Self, CompileError> {
+        if !workflow_collection.workflows.contains_key("in") {
+            return Err(CompileError::MissingStart);
+        }
+        // "in" is always number 0, the others are sorted so that the errors do not
+        // depend on the order of the hash map.
+        let mut names = Interner::new();
+        names.intern("in");
+        let mut other_names: Vec<&String> = workflow_collection
+            .workflows
+            .keys()
+            .filter(|&name| name != "in")
+            .collect();
+        other_names.sort();
+        for name in other_names {
+            names.intern(name);
+        }
+        let workflows: Vec<_> = (0..names.len())
+            .map(|id| &workflow_collection.workflows[names.name(id)])
+            .collect();
+        // The workflows each workflow sends parts to.
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); workflows.len()];
+        for (id, workflow) in workflows.iter().enumerate() {
+            let results = workflow
+                .comparisons
+                .iter()
+                .map(|comparison| &comparison.result)
+                .chain([&workflow.otherwise]);
+            for result in results {
+                if let WorkflowResult::NextWorkflow(name) = result {
+                    match names.get(name) {
+                        Some(next) => successors[id].push(next),
+                        None => {
+                            return Err(CompileError::UndefinedWorkflow {
+                                workflow: workflow.name.clone(),
+                                name: name.clone(),
+                            })
+                        }
+                    }
+                }
+            }
+        }
+        let order = Self::get_compile_order(&names, &successors)?;
+        // Every workflow is compiled after the workflows it sends parts to, so the
+        // nodes of the results already exist. The rules are compiled from the last to
+        // the first, so that every test knows the node that follows if it fails.
+        let mut nodes = vec![Node::Accept, Node::Reject];
+        let mut entries: Vec<usize> = vec![0; workflows.len()];
+        for id in order {
+            let get_node = |result: &WorkflowResult, entries: &[usize]| match result {
+                WorkflowResult::Accept => ACCEPT,
+                WorkflowResult::Reject => REJECT,
+                WorkflowResult::NextWorkflow(name) => entries[names.get(name).unwrap()],
+            };
+            let mut next = get_node(&workflows[id].otherwise, &entries);
+            for comparison in workflows[id].comparisons.iter().rev() {
+                nodes.push(Node::Test {
+                    category: comparison.category,
+                    operator: comparison.operator,
+                    value: comparison.value,
+                    then: get_node(&comparison.result, &entries),
+                    otherwise: next,
+                });
+                next = nodes.len() - 1;
+            }
+            entries[id] = next;
+        }
+        Ok(Self {
+            nodes,
+            names,
+            entries,
+        })
+    }
+
+    /// Function that orders the workflows so that every workflow comes after all
+    /// workflows it sends parts to (depth first search). Fails if there is a cycle.
+    fn get_compile_order(
+        names: &Interner,
+        successors: &[Vec<usize>],
+    ) -> Result<Vec<usize>, CompileError> {
+        fn visit(
+            id: usize,
+            successors: &[Vec<usize>],
+            path: &mut Vec<usize>,
+            done: &mut [bool],
+            order: &mut Vec<usize>,
+        ) -> Result<(), Vec<usize>> {
+            if done[id] {
+                return Ok(());
+            }
+            if let Some(start) = path.iter().position(|&other| other == id) {
+                let mut cycle = path[start..].to_vec();
+                cycle.push(id);
+                return Err(cycle);
+            }
+            path.push(id);
+            for &next in successors[id].iter() {
+                visit(next, successors, path, done, order)?;
+            }
+            path.pop();
+            done[id] = true;
+            order.push(id);
+            Ok(())
+        }
+        let mut done = vec![false; successors.len()];
+        let mut order: Vec<usize> = Vec::new();
+        for id in 0..successors.len() {
+            visit(id, successors, &mut Vec::new(), &mut done, &mut order).map_err(|cycle| {
+                CompileError::Cycle(cycle.iter().map(|&id| names.name(id).to_string()).collect())
+            })?;
+        }
+        Ok(order)
+    }
+
+    /// Function that returns the first node of the workflow "in".
+    pub fn start(&self) -> usize {
+        self.entries[0]
+    }
+
+    /// Function that follows the tests from the node until the part is accepted or
+    /// rejected.
+    pub fn is_accepted_from(&self, mut node: usize, part: &Part) -> bool {
+        loop {
+            match self.nodes[node] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Test {
+                    category,
+                    operator,
+                    value,
+                    then,
+                    otherwise,
+                } => {
+                    node = if operator.apply(part.get(category), value) {
+                        then
+                    } else {
+                        otherwise
+                    };
+                }
+            }
+        }
+    }
+
+    pub fn is_accepted(&self, part: &Part) -> bool {
+        self.is_accepted_from(self.start(), part)
+    }
+
+    /// Function that finds the boxes of ratings within the bounds that are accepted
+    /// when starting at the node. Every test splits a box into the part that passes
+    /// and the part that does not, so the boxes do not overlap.
+    pub fn accepted_boxes_from(&self, node: usize, bounds: &PartRanges) -> Vec<PartRanges> {
+        let mut accepted_boxes: Vec<PartRanges> = Vec::new();
+        let mut todo: Vec<(usize, PartRanges)> = vec![(node, *bounds)];
+        while let Some((node, part_ranges)) = todo.pop() {
+            match self.nodes[node] {
+                Node::Accept => accepted_boxes.push(part_ranges),
+                Node::Reject => (),
+                Node::Test {
+                    category,
+                    operator,
+                    value,
+                    then,
+                    otherwise,
+                } => {
+                    let (passed, failed) = operator.split(&part_ranges, category, value);
+                    todo.extend(failed.map(|failed| (otherwise, failed)));
+                    todo.extend(passed.map(|passed| (then, passed)));
+                }
+            }
+        }
+        accepted_boxes
+    }
+
+    /// Function that finds the boxes of ratings within the bounds that are accepted
+    /// (starting at the workflow "in").
+    pub fn accepted_boxes(&self, bounds: &PartRanges) -> Vec<PartRanges> {
+        self.accepted_boxes_from(self.start(), bounds)
+    }
+
+    /// Function that finds the boxes of ratings within the bounds that the workflow
+    /// accepts (None if there is no such workflow).
+    pub fn accepted_boxes_of_workflow(
+        &self,
+        name: &str,
+        bounds: &PartRanges,
+    ) -> Option<Vec<PartRanges>> {
+        let id = self.names.get(name)?;
+        Some(self.accepted_boxes_from(self.entries[id], bounds))
+    }
+
+    /// Function that sums up the ratings of all accepted parts.
+    pub fn sum_accepted_ratings(&self, parts: &[Part]) -> usize {
+        parts
+            .iter()
+            .filter(|part| self.is_accepted(part))
+            .map(Part::get_rating_sum)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::Workflow;
+
+    fn compile(lines: &[&str]) -> Result<DecisionTree, CompileError> {
+        let mut workflow_collection = WorkflowCollection::new();
+        for line in lines {
+            let workflow = Workflow::from_line(line.to_string()).unwrap();
+            workflow_collection
+                .workflows
+                .insert(workflow.name.clone(), workflow);
+        }
+        DecisionTree::compile(&workflow_collection)
+    }
+
+    #[test]
+    fn test_compile() {
+        let tree = compile(&["in{x>10:ab,R}", "ab{m<5:A,cd}", "cd{A}"]).unwrap();
+        // "cd" needs no node of its own, it always accepts.
+        assert_eq!(tree.nodes.len(), 4);
+        assert_eq!(tree.entries[tree.names.get("cd").unwrap()], ACCEPT);
+        let part = |x, m| Part { x, m, a: 1, s: 1 };
+        assert!(!tree.is_accepted(&part(10, 1)));
+        assert!(tree.is_accepted(&part(11, 1)));
+        assert!(tree.is_accepted(&part(11, 100)));
+        assert_eq!(tree.sum_accepted_ratings(&[part(1, 1), part(20, 1)]), 23);
+    }
+
+    #[test]
+    fn test_accepted_boxes() {
+        let tree = compile(&["in{x>10:ab,R}", "ab{m<5:A,cd}", "cd{s>2:R,A}"]).unwrap();
+        let bounds = PartRanges::new([(1, 20), (1, 10), (1, 1), (1, 5)]);
+        assert_eq!(
+            tree.accepted_boxes(&bounds),
+            vec![
+                PartRanges::new([(11, 20), (1, 4), (1, 1), (1, 5)]),
+                PartRanges::new([(11, 20), (5, 10), (1, 1), (1, 2)])
+            ]
+        );
+        assert_eq!(
+            tree.accepted_boxes_of_workflow("cd", &bounds),
+            Some(vec![PartRanges::new([(1, 20), (1, 10), (1, 1), (1, 2)])])
+        );
+        assert_eq!(tree.accepted_boxes_of_workflow("xy", &bounds), None);
+        // No rating is greater than the largest value.
+        let tree = compile(&["in{x>18446744073709551615:R,A}"]).unwrap();
+        assert_eq!(tree.accepted_boxes(&bounds), vec![bounds]);
+    }
+
+    #[test]
+    fn test_compile_error() {
+        assert_eq!(compile(&["ab{A}"]).unwrap_err(), CompileError::MissingStart);
+        assert_eq!(
+            compile(&["in{x>10:ab,R}", "ab{m<5:A,cd}"]).unwrap_err(),
+            CompileError::UndefinedWorkflow {
+                workflow: String::from("ab"),
+                name: String::from("cd")
+            }
+        );
+        let error = compile(&["in{x>10:ab,R}", "ab{m<5:A,cd}", "cd{a>1:ab,R}"]).unwrap_err();
+        assert_eq!(
+            error,
+            CompileError::Cycle(vec![
+                String::from("ab"),
+                String::from("cd"),
+                String::from("ab")
+            ])
+        );
+        assert_eq!(
+            error.into_parse_error(2),
+            ParseError::new(2, 1, "workflows without a cycle").found("'ab -> cd -> ab'")
+        );
+    }
+}
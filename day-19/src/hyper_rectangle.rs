@@ -0,0 +1,96 @@
+/// A box of all points whose coordinates are within the bounds (one inclusive range
+/// per dimension). A box where a lower bound is larger than the upper bound is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HyperRectangle<const N: usize> {
+    pub bounds: [(usize, usize); N],
+}
+
+impl<const N: usize> HyperRectangle<N> {
+    pub fn new(bounds: [(usize, usize); N]) -> Self {
+        Self { bounds }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.iter().any(|&(low, high)| low > high)
+    }
+
+    /// Function that returns the number of points in the box.
+    pub fn volume(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        self.bounds
+            .iter()
+            .map(|&(low, high)| high - low + 1)
+            .product()
+    }
+
+    pub fn contains(&self, point: &[usize; N]) -> bool {
+        self.bounds
+            .iter()
+            .zip(point)
+            .all(|(&(low, high), &coordinate)| low <= coordinate && coordinate <= high)
+    }
+
+    /// Function that splits the box into the points whose coordinate of the dimension
+    /// is less than the value and the others. Parts that would be empty are None.
+    pub fn split_at(&self, dimension: usize, value: usize) -> (Option<Self>, Option<Self>) {
+        let (low, high) = self.bounds[dimension];
+        let mut below = *self;
+        let mut above = *self;
+        below.bounds[dimension].1 = high.min(value.saturating_sub(1));
+        above.bounds[dimension].0 = low.max(value);
+        // Nothing is less than 0 (the upper bound would have to be -1).
+        let below = (value > 0 && !below.is_empty()).then_some(below);
+        let above = (!above.is_empty()).then_some(above);
+        (below, above)
+    }
+
+    /// Function that splits the box into the points whose coordinate of the dimension
+    /// is greater than the value and the others. Parts that would be empty are None.
+    pub fn split_above(&self, dimension: usize, value: usize) -> (Option<Self>, Option<Self>) {
+        match value.checked_add(1) {
+            Some(threshold) => {
+                let (below, above) = self.split_at(dimension, threshold);
+                (above, below)
+            }
+            // Nothing is greater than the largest value.
+            None => (None, Some(*self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let rectangle = HyperRectangle::new([(1, 10), (5, 6)]);
+        assert_eq!(rectangle.volume(), 20);
+        assert_eq!(
+            rectangle.split_at(0, 4),
+            (
+                Some(HyperRectangle::new([(1, 3), (5, 6)])),
+                Some(HyperRectangle::new([(4, 10), (5, 6)]))
+            )
+        );
+        assert_eq!(rectangle.split_at(1, 5), (None, Some(rectangle)));
+        assert_eq!(rectangle.split_at(1, 0), (None, Some(rectangle)));
+        assert_eq!(
+            rectangle.split_above(1, 5),
+            (
+                Some(HyperRectangle::new([(1, 10), (6, 6)])),
+                Some(HyperRectangle::new([(1, 10), (5, 5)]))
+            )
+        );
+        assert_eq!(rectangle.split_above(0, 10), (None, Some(rectangle)));
+        assert_eq!(
+            rectangle.split_above(0, usize::MAX),
+            (None, Some(rectangle))
+        );
+        assert!(rectangle.contains(&[10, 5]));
+        assert!(!rectangle.contains(&[10, 7]));
+        assert_eq!(HyperRectangle::new([(2, 1), (1, 4)]).volume(), 0);
+    }
+}
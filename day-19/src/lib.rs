@@ -0,0 +1,126 @@
+use common::{read_lines, Answer, ParseError, Solution};
+use std::{collections::HashMap, io::BufRead};
+
+mod analysis;
+mod decision_tree;
+mod hyper_rectangle;
+mod workflow;
+pub use analysis::Analysis;
+pub use decision_tree::{CompileError, DecisionTree, Node};
+pub use hyper_rectangle::HyperRectangle;
+pub use workflow::*;
+
+/// Function that reads the workflows and the parts (separated by an empty line) and
+/// compiles the workflows.
+fn parse_input<B: BufRead>(
+    reader: B,
+) -> Result<(WorkflowCollection, DecisionTree, Vec<Part>), ParseError> {
+    let mut workflow_collection = WorkflowCollection::new();
+    let mut workflow_lines: HashMap<String, usize> = HashMap::new();
+    let mut parts: Vec<Part> = Vec::new();
+    let mut read_workflow = true;
+    let mut number_of_lines = 0;
+    for (idx, line) in read_lines(reader)?.into_iter().enumerate() {
+        number_of_lines = idx + 1;
+        if line.is_empty() {
+            read_workflow = false;
+        } else if read_workflow {
+            let workflow = Workflow::from_line(line).map_err(|e| e.with_line(idx + 1))?;
+            if workflow_lines.contains_key(&workflow.name) {
+                return Err(ParseError::new(idx + 1, 1, "a new workflow name")
+                    .found(format!("'{}'", workflow.name)));
+            }
+            workflow_lines.insert(workflow.name.clone(), idx + 1);
+            workflow_collection
+                .workflows
+                .insert(workflow.name.clone(), workflow);
+        } else {
+            parts.push(Part::from_line(line).map_err(|e| e.with_line(idx + 1))?);
+        }
+    }
+    // Problems that are not in one workflow (no "in") are reported at the end.
+    let decision_tree = DecisionTree::compile(&workflow_collection).map_err(|error| {
+        let line = error
+            .workflow()
+            .map_or(number_of_lines + 1, |name| workflow_lines[name]);
+        error.into_parse_error(line)
+    })?;
+    Ok((workflow_collection, decision_tree, parts))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (WorkflowCollection, DecisionTree, Vec<Part>);
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_input(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(
+        (_, decision_tree, parts): &Self::Input,
+        _parameters: &Self::Parameters,
+    ) -> Answer {
+        decision_tree.sum_accepted_ratings(parts).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(
+        (_, decision_tree, _): &Self::Input,
+        _parameters: &Self::Parameters,
+    ) -> Answer {
+        decision_tree
+            .accepted_boxes(&RATING_BOUNDS)
+            .iter()
+            .map(PartRanges::volume)
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, Cursor},
+    };
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day19::solve_first_task(&Day19::parse(reader).unwrap(), &()),
+            Answer::Unsigned(19114)
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "in{x>10:ab,R}\nab{m<5:A,cd}\n\n{x=1,m=2,a=3,s=4}";
+        assert_eq!(
+            Day19::parse(Cursor::new(input)).unwrap_err(),
+            ParseError::new(2, 1, "only names of existing workflows").found("'cd'")
+        );
+        let input = "in{x>10:ab,R}\nab{m<5:A,R}\nin{A}";
+        assert_eq!(
+            Day19::parse(Cursor::new(input)).unwrap_err(),
+            ParseError::new(3, 1, "a new workflow name").found("'in'")
+        );
+        assert_eq!(
+            Day19::parse(Cursor::new("ab{A}\n")).unwrap_err(),
+            ParseError::new(2, 1, "a workflow named 'in'").found("none")
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day19::solve_second_task(&Day19::parse(reader).unwrap(), &()),
+            Answer::Unsigned(167409079868000)
+        );
+    }
+}
@@ -1,72 +1,164 @@
-use std::{cmp::Ordering, collections::HashMap};
+use crate::hyper_rectangle::HyperRectangle;
+use common::{LineParser, ParseError};
+use std::{collections::HashMap, fmt};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum WorkflowResult {
     Accept,
     Reject,
     NextWorkflow(String),
-    NoResult,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl WorkflowResult {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "A" => WorkflowResult::Accept,
+            "R" => WorkflowResult::Reject,
+            _ => WorkflowResult::NextWorkflow(name.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for WorkflowResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkflowResult::Accept => write!(f, "A"),
+            WorkflowResult::Reject => write!(f, "R"),
+            WorkflowResult::NextWorkflow(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The category of a rating: e(x)tremely cool looking, (m)usical, (a)erodynamic or
+/// (s)hiny.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [Category::X, Category::M, Category::A, Category::S];
+
+    pub fn as_char(&self) -> char {
+        match self {
+            Category::X => 'x',
+            Category::M => 'm',
+            Category::A => 'a',
+            Category::S => 's',
+        }
+    }
+
+    /// Function that returns the dimension of the category in `PartRanges`.
+    pub fn dimension(&self) -> usize {
+        *self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Less,
+    Greater,
+}
+
+impl Operator {
+    pub fn as_char(&self) -> char {
+        match self {
+            Operator::Less => '<',
+            Operator::Greater => '>',
+        }
+    }
+
+    /// Function that checks if the rating is less or greater than the value.
+    pub fn apply(&self, rating: usize, value: usize) -> bool {
+        match self {
+            Operator::Less => rating < value,
+            Operator::Greater => rating > value,
+        }
+    }
+
+    /// Function that splits the ranges into the parts whose rating of the category
+    /// passes the comparison with the value and the parts that do not.
+    pub fn split(
+        &self,
+        part_ranges: &PartRanges,
+        category: Category,
+        value: usize,
+    ) -> (Option<PartRanges>, Option<PartRanges>) {
+        match self {
+            Operator::Less => part_ranges.split_at(category.dimension(), value),
+            Operator::Greater => part_ranges.split_above(category.dimension(), value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Comparison {
-    pub category: char,
-    pub comparison_char: char,
-    pub comparison_function: fn(&usize, &usize) -> Ordering,
+    pub category: Category,
+    pub operator: Operator,
     pub value: usize,
     pub result: WorkflowResult,
 }
 
 impl Comparison {
-    pub fn from_string(s: &str) -> Self {
+    pub fn from_string(s: &str) -> Result<Self, ParseError> {
+        let mut parser = LineParser::new(s);
+        let comparison = Self::parse(&mut parser)?;
+        parser.end()?;
+        Ok(comparison)
+    }
+
+    /// Function that parses a comparison at the current position of the parser.
+    fn parse(parser: &mut LineParser) -> Result<Self, ParseError> {
         // Example: a<2006:qkq
         // Example: m>2090:A
-        let mut category: char = '?';
-        let mut comparison_char: char = '?';
-        let mut comparison_function: fn(&usize, &usize) -> Ordering = usize::cmp;
-        let mut value: usize = 0;
-        let mut result_str: String = String::from("");
-        for (idx, c) in s.bytes().enumerate() {
-            if idx == 0 {
-                category = c as char;
-            } else if idx == 1 {
-                comparison_function = match c {
-                    b'<' => |a, b| usize::cmp(b, a),
-                    b'>' => usize::cmp,
-                    _ => unreachable!(),
-                };
-                comparison_char = c as char;
-            } else if c.is_ascii_digit() {
-                value = value * 10 + (c - b'0') as usize;
-            } else if c == b':' {
-                // Do nothing.
-            } else {
-                result_str.push(c as char);
-            }
-        }
-        Self {
+        let category = match parser.one_of("xmas")? {
+            'x' => Category::X,
+            'm' => Category::M,
+            'a' => Category::A,
+            _ => Category::S,
+        };
+        let operator = match parser.one_of("<>")? {
+            '<' => Operator::Less,
+            _ => Operator::Greater,
+        };
+        let value = parser.number::<usize>()?;
+        parser.expect(":")?;
+        let result = WorkflowResult::from_name(parser.word()?);
+        Ok(Self {
             category,
-            comparison_char,
-            comparison_function,
+            operator,
             value,
-            result: match result_str.as_str() {
-                "A" => WorkflowResult::Accept,
-                "R" => WorkflowResult::Reject,
-                _ => WorkflowResult::NextWorkflow(result_str),
-            },
-        }
+            result,
+        })
     }
 
-    pub fn compare(&self, xmas_value: usize) -> WorkflowResult {
-        if (self.comparison_function)(&self.value, &xmas_value) == Ordering::Less {
-            return self.result.clone();
-        } else {
-            return WorkflowResult::NoResult;
-        }
+    /// Function that returns the result if the rating passes the comparison and None
+    /// if the next rule has to be checked.
+    pub fn compare(&self, xmas_value: usize) -> Option<WorkflowResult> {
+        self.operator
+            .apply(xmas_value, self.value)
+            .then(|| self.result.clone())
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+impl fmt::Display for Comparison {
+    /// Writes the comparison like in the puzzle input, e.g. "a<2006:qkq".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}:{}",
+            self.category.as_char(),
+            self.operator.as_char(),
+            self.value,
+            self.result
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Workflow {
     pub name: String,
     pub comparisons: Vec<Comparison>,
@@ -74,74 +166,72 @@ pub struct Workflow {
 }
 
 impl Workflow {
-    pub fn from_line(line: String) -> Self {
+    pub fn from_line(line: String) -> Result<Self, ParseError> {
         // Example line: px{a<2006:qkq,m>2090:A,rfg}
-        let mut parts = line.split('{');
-        let name: String = parts.next().unwrap().to_string();
-        let comparison_parts: Vec<&str> = parts.next().unwrap().split(',').collect();
+        let mut parser = LineParser::new(&line);
+        let name = parser.word()?.to_string();
+        parser.expect("{")?;
         let mut comparisons: Vec<Comparison> = Vec::new();
-        for part in comparison_parts.iter().take(comparison_parts.len() - 1) {
-            comparisons.push(Comparison::from_string(part));
+        loop {
+            // A comparison starts with a category followed by '<' or '>', otherwise it
+            // is the last rule that only names the result.
+            let mut lookahead = parser.clone();
+            if lookahead.one_of("xmas").is_err() || lookahead.one_of("<>").is_err() {
+                break;
+            }
+            comparisons.push(Comparison::parse(&mut parser)?);
+            parser.expect(",")?;
         }
-        let mut otherwise_str = comparison_parts.last().unwrap().to_string();
-        otherwise_str.pop();
-        Self {
+        let otherwise = WorkflowResult::from_name(parser.word()?);
+        parser.expect("}")?;
+        parser.end()?;
+        Ok(Self {
             name,
             comparisons,
-            otherwise: match otherwise_str.as_str() {
-                "A" => WorkflowResult::Accept,
-                "R" => WorkflowResult::Reject,
-                _ => WorkflowResult::NextWorkflow(otherwise_str),
-            },
-        }
+            otherwise,
+        })
     }
 
     pub fn compare(&self, part: &Part) -> WorkflowResult {
+        self.comparisons
+            .iter()
+            .find_map(|comparison| comparison.compare(part.get(comparison.category)))
+            .unwrap_or_else(|| self.otherwise.clone())
+    }
+}
+
+impl fmt::Display for Workflow {
+    /// Writes the workflow like in the puzzle input, e.g. "px{a<2006:qkq,m>2090:A,rfg}".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{{", self.name)?;
         for comparison in self.comparisons.iter() {
-            let result = comparison.compare(match comparison.category {
-                'x' => part.x,
-                'm' => part.m,
-                'a' => part.a,
-                's' => part.s,
-                _ => unreachable!(),
-            });
-            if result != WorkflowResult::NoResult {
-                return result;
-            }
+            write!(f, "{},", comparison)?;
         }
-        self.otherwise.clone()
+        write!(f, "{}}}", self.otherwise)
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Default)]
 pub struct WorkflowCollection {
     pub workflows: HashMap<String, Workflow>,
 }
 
 impl WorkflowCollection {
     pub fn new() -> Self {
-        Self {
-            workflows: HashMap::new(),
-        }
+        Self::default()
     }
+}
 
-    pub fn check_acceptance(&self, part: &Part) -> bool {
-        let mut workflow_name = String::from("in");
-        loop {
-            match self.workflows.get(&workflow_name) {
-                Some(workflow) => match workflow.compare(&part) {
-                    WorkflowResult::Accept => return true,
-                    WorkflowResult::Reject => return false,
-                    WorkflowResult::NextWorkflow(next_workflow_name) => {
-                        workflow_name = next_workflow_name;
-                    }
-                    WorkflowResult::NoResult => panic!(),
-                },
-                None => {
-                    panic!()
-                }
-            }
+impl fmt::Display for WorkflowCollection {
+    /// Writes one workflow per line like in the puzzle input, "in" first and the
+    /// others sorted by name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<&String> = self.workflows.keys().collect();
+        names.sort_by_key(|&name| (name != "in", name));
+        for name in names {
+            writeln!(f, "{}", self.workflows[name])?;
         }
+        Ok(())
     }
 }
 
@@ -154,49 +244,51 @@ pub struct Part {
 }
 
 impl Part {
-    pub fn from_line(line: String) -> Self {
+    pub fn from_line(line: String) -> Result<Self, ParseError> {
         // Example line: {x=787,m=2655,a=1222,s=2876}
-        let mut number: usize = 0;
-        let mut xmas: [usize; 4] = [0; 4];
-        let mut idx = 0;
-        for c in line.bytes().skip(3) {
-            if c.is_ascii_digit() {
-                number = number * 10 + (c - b'0') as usize;
-            } else if number > 0 {
-                xmas[idx] = number;
-                number = 0;
-                idx += 1;
-            }
-        }
-        Self {
-            x: xmas[0],
-            m: xmas[1],
-            a: xmas[2],
-            s: xmas[3],
+        let mut parser = LineParser::new(&line);
+        parser.expect("{x=")?;
+        let x = parser.number()?;
+        parser.expect(",m=")?;
+        let m = parser.number()?;
+        parser.expect(",a=")?;
+        let a = parser.number()?;
+        parser.expect(",s=")?;
+        let s = parser.number()?;
+        parser.expect("}")?;
+        parser.end()?;
+        Ok(Self { x, m, a, s })
+    }
+
+    pub fn get(&self, category: Category) -> usize {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
         }
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct PartRanges {
-    pub x: (usize, usize),
-    pub m: (usize, usize),
-    pub a: (usize, usize),
-    pub s: (usize, usize),
+    pub fn get_rating_sum(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
 }
 
-impl PartRanges {
-    pub fn is_valid(&self) -> bool {
-        self.x.1 >= self.x.0 && self.m.1 >= self.m.0 && self.a.1 >= self.a.0 && self.s.1 >= self.s.0
-    }
-    pub fn get_score(&self) -> usize {
-        (self.x.1 - self.x.0 + 1)
-            * (self.m.1 - self.m.0 + 1)
-            * (self.a.1 - self.a.0 + 1)
-            * (self.s.1 - self.s.0 + 1)
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
     }
 }
 
+/// The ranges of the ratings of many parts, one dimension per category (in the order
+/// of `Category::ALL`).
+pub type PartRanges = HyperRectangle<4>;
+
+/// The ratings of every category are between 1 and 4000.
+pub const RATING_BOUNDS: PartRanges = HyperRectangle {
+    bounds: [(1, 4000); 4],
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,7 +296,7 @@ mod tests {
     #[test]
     fn test_part_from_line() {
         assert_eq!(
-            Part::from_line(String::from("{x=787,m=2655,a=1222,s=2876}")),
+            Part::from_line(String::from("{x=787,m=2655,a=1222,s=2876}")).unwrap(),
             Part {
                 x: 787,
                 m: 2655,
@@ -214,39 +306,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Part::from_line(String::from("{x=787,m=2655,s=2876}")).unwrap_err(),
+            ParseError::new(1, 14, "',a='").found("','")
+        );
+        assert_eq!(
+            Workflow::from_line(String::from("px{a=2006:qkq,rfg}")).unwrap_err(),
+            ParseError::new(1, 5, "'}'").found("'='")
+        );
+    }
+
     #[test]
     fn test_comparison() {
-        let comparison = Comparison::from_string("a<2006:qkq");
+        let comparison = Comparison::from_string("a<2006:qkq").unwrap();
         assert_eq!(
             comparison.compare(100),
-            WorkflowResult::NextWorkflow(String::from("qkq"))
+            Some(WorkflowResult::NextWorkflow(String::from("qkq")))
         );
-        let comparison = Comparison::from_string("a<2006:qkq");
-        assert_eq!(comparison.compare(5000), WorkflowResult::NoResult);
-        let comparison = Comparison::from_string("a>2006:qkq");
-        assert_eq!(comparison.compare(100), WorkflowResult::NoResult);
-        let comparison = Comparison::from_string("a<2006:A");
-        assert_eq!(comparison.compare(100), WorkflowResult::Accept);
-        let comparison = Comparison::from_string("a<2006:R");
-        assert_eq!(comparison.compare(100), WorkflowResult::Reject);
+        let comparison = Comparison::from_string("a<2006:qkq").unwrap();
+        assert_eq!(comparison.compare(5000), None);
+        let comparison = Comparison::from_string("a>2006:qkq").unwrap();
+        assert_eq!(comparison.compare(100), None);
+        let comparison = Comparison::from_string("a<2006:A").unwrap();
+        assert_eq!(comparison.compare(100), Some(WorkflowResult::Accept));
+        let comparison = Comparison::from_string("a<2006:R").unwrap();
+        assert_eq!(comparison.compare(100), Some(WorkflowResult::Reject));
     }
 
     #[test]
     fn test_workflow() {
-        let workflow = Workflow::from_line(String::from("ex{x>10:one,m<20:two,a>30:R,A}"));
-        let part = Part::from_line(String::from("{x=11,m=1,a=1,s=1}"));
+        let workflow = Workflow::from_line(String::from("ex{x>10:one,m<20:two,a>30:R,A}")).unwrap();
+        let part = Part::from_line(String::from("{x=11,m=1,a=1,s=1}")).unwrap();
         assert_eq!(
             workflow.compare(&part),
             WorkflowResult::NextWorkflow("one".to_string())
         );
-        let part = Part::from_line(String::from("{x=10,m=1,a=1,s=1}"));
+        let part = Part::from_line(String::from("{x=10,m=1,a=1,s=1}")).unwrap();
         assert_eq!(
             workflow.compare(&part),
             WorkflowResult::NextWorkflow("two".to_string())
         );
-        let part = Part::from_line(String::from("{x=10,m=100,a=100,s=1}"));
+        let part = Part::from_line(String::from("{x=10,m=100,a=100,s=1}")).unwrap();
         assert_eq!(workflow.compare(&part), WorkflowResult::Reject);
-        let part = Part::from_line(String::from("{x=10,m=100,a=10,s=1}"));
+        let part = Part::from_line(String::from("{x=10,m=100,a=10,s=1}")).unwrap();
         assert_eq!(workflow.compare(&part), WorkflowResult::Accept);
+        assert_eq!(workflow.to_string(), "ex{x>10:one,m<20:two,a>30:R,A}");
+        assert_eq!(part.to_string(), "{x=10,m=100,a=10,s=1}");
     }
 }
@@ -0,0 +1,27 @@
+//! Prints the module network of a day 20 input as Graphviz DOT or as Mermaid
+//! flowchart, e.g.:
+//!
+//! cargo run --release -p day-20 --example network -- day-20/input dot | dot -Tsvg > network.svg
+use common::Solution;
+use day_20::Day20;
+use std::{env, fs::File, io::BufReader, process::exit};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (input, format) = match &args[..] {
+        [input, format] if format == "dot" || format == "mermaid" => (input, format),
+        _ => {
+            eprintln!("Usage: network <input> <dot|mermaid>");
+            exit(2);
+        }
+    };
+    let reader = BufReader::new(File::open(input).expect("Input file not found."));
+    let module_collection = Day20::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(input.clone()));
+        exit(1);
+    });
+    match format.as_str() {
+        "dot" => println!("{}", module_collection.to_dot()),
+        _ => print!("{}", module_collection.to_mermaid()),
+    }
+}
@@ -0,0 +1,50 @@
+//! Records the signals of the first button presses of a day 20 input and writes them
+//! as VCD waveform or CSV (chosen by the extension of the output file), e.g.:
+//!
+//! cargo run --release -p day-20 --example trace -- day-20/input 10 trace.vcd
+use common::Solution;
+use day_20::Day20;
+use std::{
+    env,
+    fs::File,
+    io::{BufReader, BufWriter},
+    process::exit,
+};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (input, presses, output) = match &args[..] {
+        [input, presses, output] => match presses.parse::<usize>() {
+            Ok(presses) => (input, presses, output),
+            Err(_) => {
+                eprintln!("error: invalid number of presses '{}'", presses);
+                exit(2);
+            }
+        },
+        _ => {
+            eprintln!("Usage: trace <input> <presses> <output.vcd|output.csv>");
+            exit(2);
+        }
+    };
+    let reader = BufReader::new(File::open(input).expect("Input file not found."));
+    let mut module_collection = Day20::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(input.clone()));
+        exit(1);
+    });
+    module_collection.enable_tracing();
+    for _ in 0..presses {
+        module_collection.press_button();
+    }
+    let trace = module_collection.take_trace().unwrap();
+    let writer = BufWriter::new(File::create(output).expect("Cannot create the output file."));
+    let result = if output.ends_with(".csv") {
+        trace.write_csv(writer)
+    } else {
+        trace.write_vcd(writer)
+    };
+    if let Err(error) = result {
+        eprintln!("error: cannot write {}: {}", output, error);
+        exit(1);
+    }
+    println!("Wrote {} signals to {}.", trace.signals.len(), output);
+}
@@ -0,0 +1,154 @@
+use crate::{ModuleCollection, ModuleType, SignalType};
+use graph::{Cluster, NodeId};
+
+impl ModuleCollection {
+    /// Function that returns the name of the module with its prefix ('%' for
+    /// flip-flops, '&' for conjunctions) like in the puzzle input.
+    fn get_label(&self, node: NodeId) -> String {
+        let prefix = match self.modules[node] {
+            Some(ModuleType::FlipFlop(_)) => "%",
+            Some(ModuleType::Conjunction(_)) => "&",
+            _ => "",
+        };
+        format!("{}{}", prefix, self.connections.name(node))
+    }
+
+    /// Function that groups the sub-counters: For every receiver that is no module
+    /// (e.g. "rx") and gets its Low pulse from one conjunction with independent inputs
+    /// (see `get_sub_counters`), every input and the modules feeding it form a
+    /// cluster.
+    fn get_clusters(&self) -> Vec<Cluster> {
+        let mut clusters: Vec<Cluster> = Vec::new();
+        let mut in_cluster = vec![false; self.connections.node_count()];
+        let receivers = self
+            .connections
+            .nodes()
+            .filter(|&node| self.modules[node].is_none() && node != self.button);
+        for receiver in receivers {
+            let inputs = match self.get_sub_counters(receiver, &SignalType::Low) {
+                Ok((_, inputs)) => inputs,
+                Err(_) => continue,
+            };
+            for input in inputs {
+                let node = self.connections.node_id(&input).unwrap();
+                let mut nodes: Vec<NodeId> = self.get_upstream_modules(node).into_iter().collect();
+                nodes.push(node);
+                nodes.retain(|&node| !in_cluster[node]);
+                nodes.sort();
+                for &node in nodes.iter() {
+                    in_cluster[node] = true;
+                }
+                clusters.push(Cluster {
+                    label: format!("sub-counter {}", input),
+                    nodes,
+                });
+            }
+        }
+        clusters
+    }
+
+    /// Function that creates a description of the network in the DOT language of
+    /// Graphviz. Flip-flops are blue boxes, conjunctions orange diamonds and the
+    /// sub-counters are drawn in boxes of their own.
+    pub fn to_dot(&self) -> String {
+        let attributes = |node: NodeId| {
+            let style = match self.modules[node] {
+                Some(ModuleType::Broadcaster) => "shape=doublecircle",
+                Some(ModuleType::FlipFlop(_)) => "shape=box, style=filled, fillcolor=lightblue",
+                Some(ModuleType::Conjunction(_)) => "shape=diamond, style=filled, fillcolor=orange",
+                None => "shape=plaintext",
+            };
+            format!("label=\"{}\", {}", self.get_label(node), style)
+        };
+        self.connections
+            .to_dot_with(attributes, &self.get_clusters())
+    }
+
+    /// Function that creates a Mermaid flowchart of the network with the same styles
+    /// and clusters as `to_dot`. The nodes are called by their number, so that names
+    /// like "end" do not clash with the keywords of Mermaid.
+    pub fn to_mermaid(&self) -> String {
+        let node_line = |node: NodeId, indent: &str| {
+            let label = self.get_label(node);
+            let shape = match self.modules[node] {
+                Some(ModuleType::Broadcaster) => format!("([\"{}\"])", label),
+                Some(ModuleType::FlipFlop(_)) => format!("[\"{}\"]:::flipFlop", label),
+                Some(ModuleType::Conjunction(_)) => format!("{{\"{}\"}}:::conjunction", label),
+                None => format!("((\"{}\"))", label),
+            };
+            format!("{}n{}{}\n", indent, node, shape)
+        };
+        let mut mermaid = String::from("flowchart LR\n");
+        mermaid.push_str("    classDef flipFlop fill:lightblue\n");
+        mermaid.push_str("    classDef conjunction fill:orange\n");
+        let mut in_cluster = vec![false; self.connections.node_count()];
+        for (idx, cluster) in self.get_clusters().iter().enumerate() {
+            mermaid.push_str(&format!("    subgraph c{} [\"{}\"]\n", idx, cluster.label));
+            for &node in cluster.nodes.iter() {
+                in_cluster[node] = true;
+                mermaid.push_str(&node_line(node, "        "));
+            }
+            mermaid.push_str("    end\n");
+        }
+        for node in self.connections.nodes().filter(|&node| !in_cluster[node]) {
+            mermaid.push_str(&node_line(node, "    "));
+        }
+        for sender in self.connections.nodes() {
+            for receiver in self.connections.neighbors(sender) {
+                mermaid.push_str(&format!("    n{} --> n{}\n", sender, receiver));
+            }
+        }
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ModuleCollection;
+    use std::io::Cursor;
+
+    fn example() -> ModuleCollection {
+        // Two independent sub-counters ("ia" and "ib") that feed "s".
+        let network = "broadcaster -> a1, b1
+%a1 -> ka
+&ka -> ia
+&ia -> s
+%b1 -> ib
+&ib -> s
+&s -> rx";
+        ModuleCollection::from_reader(Cursor::new(network)).unwrap()
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = example().to_dot();
+        assert!(dot.starts_with(
+            "digraph {
+    subgraph cluster_0 {
+        label=\"sub-counter ia\";
+        \"a1\" [label=\"%a1\", shape=box, style=filled, fillcolor=lightblue];
+        \"ka\" [label=\"&ka\", shape=diamond, style=filled, fillcolor=orange];
+        \"ia\" [label=\"&ia\", shape=diamond, style=filled, fillcolor=orange];
+    }
+    subgraph cluster_1 {
+        label=\"sub-counter ib\";"
+        ));
+        assert!(dot.contains("    \"rx\" [label=\"rx\", shape=plaintext];\n"));
+        assert!(dot.contains("    \"button\" -> \"broadcaster\";\n"));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mermaid = example().to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains(
+            "    subgraph c1 [\"sub-counter ib\"]
+        n2[\"%b1\"]:::flipFlop
+        n6{\"&ib\"}:::conjunction
+    end
+"
+        ));
+        assert!(mermaid.contains("    n0([\"broadcaster\"])\n"));
+        assert!(mermaid.contains("    n5 --> n7\n"));
+    }
+}
@@ -0,0 +1,791 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use graph::{Graph, NodeId};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+    io::BufRead,
+};
+
+mod export;
+mod trace;
+pub use trace::{Trace, TracedSignal};
+
+/// The number of button presses after which the simulation gives up.
+const MAX_PRESSES: usize = 100_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SignalType {
+    Low,
+    High,
+}
+
+/// The reasons why the number of button presses until a module receives a pulse can
+/// not be determined.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProbeError {
+    /// There is no module with this name (neither as sender nor as receiver).
+    UnknownModule(String),
+    /// The module did not receive the pulse within the press limit and the pulse can
+    /// not be predicted from sub-counters (only Low pulses of a conjunction can).
+    UnpredictablePulse { module: String, pulse: SignalType },
+    /// The module did not receive the pulse within the press limit and it is not fed
+    /// by exactly one conjunction, so the network can not be split into sub-counters.
+    NotFedByOneConjunction {
+        module: String,
+        senders: Vec<String>,
+    },
+    /// Two inputs of the conjunction depend on the same modules, so their cycles are
+    /// not independent.
+    SharedUpstream { first: String, second: String },
+    /// An input of the conjunction did not send a High pulse three times.
+    NoCycleFound { input: String, presses: usize },
+    /// The High pulses of an input of the conjunction are not evenly spaced.
+    IrregularCycle { input: String, presses: [usize; 3] },
+    /// The cycle of this input never lines up with the cycles of the inputs before it.
+    NoCommonPress(String),
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeError::UnknownModule(module) => write!(f, "there is no module '{}'", module),
+            ProbeError::UnpredictablePulse { module, pulse } => write!(
+                f,
+                "'{}' did not receive a {:?} pulse within {} presses and only Low pulses of a conjunction can be predicted",
+                module, pulse, MAX_PRESSES
+            ),
+            ProbeError::NotFedByOneConjunction { module, senders } => write!(
+                f,
+                "'{}' did not receive the pulse within {} presses and is fed by [{}] instead of a single conjunction, so its cycle can not be calculated",
+                module,
+                MAX_PRESSES,
+                senders.join(", ")
+            ),
+            ProbeError::SharedUpstream { first, second } => write!(
+                f,
+                "the inputs '{}' and '{}' of the conjunction share modules, so their cycles are not independent",
+                first, second
+            ),
+            ProbeError::NoCycleFound { input, presses } => write!(
+                f,
+                "the input '{}' did not send three High pulses within {} presses",
+                input, presses
+            ),
+            ProbeError::IrregularCycle { input, presses } => write!(
+                f,
+                "the input '{}' sends High pulses at the irregular presses {:?}",
+                input, presses
+            ),
+            ProbeError::NoCommonPress(input) => write!(
+                f,
+                "the cycle of the input '{}' never lines up with the cycles of the other inputs",
+                input
+            ),
+        }
+    }
+}
+
+/// A pulse from one module to another. The modules are the nodes of the connections.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Signal {
+    sender: NodeId,
+    receiver: NodeId,
+    // The position of the sender among the inputs of the receiver.
+    input: usize,
+    signal_type: SignalType,
+}
+
+/// The last pulse a conjunction received from each of its inputs (one bit per input,
+/// set if it was High).
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+struct ConjunctionMemory {
+    high_inputs: Vec<u64>,
+    number_of_inputs: usize,
+    number_of_high_inputs: usize,
+}
+
+impl ConjunctionMemory {
+    fn new(number_of_inputs: usize) -> Self {
+        Self {
+            high_inputs: vec![0; number_of_inputs.div_ceil(64)],
+            number_of_inputs,
+            number_of_high_inputs: 0,
+        }
+    }
+
+    /// Function that remembers the pulse of the input and returns if the last pulses of
+    /// all inputs are High.
+    fn update(&mut self, input: usize, signal_type: SignalType) -> bool {
+        let (word, bit) = (input / 64, 1 << (input % 64));
+        let was_high = self.high_inputs[word] & bit != 0;
+        match signal_type {
+            SignalType::High if !was_high => {
+                self.high_inputs[word] |= bit;
+                self.number_of_high_inputs += 1;
+            }
+            SignalType::Low if was_high => {
+                self.high_inputs[word] &= !bit;
+                self.number_of_high_inputs -= 1;
+            }
+            _ => (),
+        }
+        self.number_of_high_inputs == self.number_of_inputs
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum ModuleType {
+    // Broadcaster: broadcaster
+    // Sends the pulse it receives to all of its connected outputs.
+    Broadcaster,
+    // FlipFlop: %
+    // A FlipFlop is either ON or OFF (default: OFF).
+    // If it receives a High pulse, it is ignored and nothing happens.
+    // If it receives a Low pulse, it flips between ON and OFF.
+    // If it changes from OFF -> ON it sends a High pulse.
+    // If it changes from ON -> OFF it sends a Low pulse.
+    FlipFlop(bool),
+    // Conjunction: &
+    // Remembers what signal ALL his inputs send to it (default: Low).
+    // If all remembered inputs are High, it sends a Low pulse.
+    // Otherwise it sends a High pulse.
+    // So for this one we need to check how many other modules transfer
+    // signals to it.
+    Conjunction(ConjunctionMemory),
+}
+
+impl ModuleType {
+    fn process_signal(&mut self, signal: &Signal) -> Option<SignalType> {
+        match self {
+            ModuleType::Broadcaster => Some(signal.signal_type),
+            ModuleType::FlipFlop(flip_flop_state) => match signal.signal_type {
+                SignalType::High => None,
+                SignalType::Low => {
+                    *flip_flop_state = !*flip_flop_state;
+                    match flip_flop_state {
+                        true => Some(SignalType::High),
+                        false => Some(SignalType::Low),
+                    }
+                }
+            },
+            ModuleType::Conjunction(memory) => {
+                // Update the last received signal from this sender and check if all
+                // remembered last signals are High or not.
+                if memory.update(signal.input, signal.signal_type) {
+                    Some(SignalType::Low)
+                } else {
+                    Some(SignalType::High)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Module {
+    name: String,
+    module_type: ModuleType,
+}
+
+impl Module {
+    /// Function that parses the module and the names of its outputs.
+    fn from_line(line: String) -> Result<(Self, Vec<String>), ParseError> {
+        // Example lines:
+        // broadcaster -> a, b, c
+        // %a -> inv, con
+        // &con -> output
+        let mut parser = LineParser::new(&line);
+        let module_type = if parser.accept("%") {
+            ModuleType::FlipFlop(false)
+        } else if parser.accept("&") {
+            ModuleType::Conjunction(ConjunctionMemory::default())
+        } else if parser.rest().starts_with("broadcaster") {
+            ModuleType::Broadcaster
+        } else {
+            return Err(parser.error("'%', '&' or 'broadcaster'"));
+        };
+        let name = parser.word()?.to_string();
+        parser.expect(" -> ")?;
+        let mut outputs: Vec<String> = vec![parser.word()?.to_string()];
+        while parser.accept(",") {
+            outputs.push(parser.word()?.to_string());
+        }
+        parser.end()?;
+        Ok((Self { name, module_type }, outputs))
+    }
+}
+
+/// A receiver of the pulses of a module.
+#[derive(Debug, Clone)]
+struct Output {
+    receiver: NodeId,
+    // The position of the sender among the inputs of the receiver.
+    input: usize,
+}
+
+/// The module network, compiled so that the modules are numbered by their node in the
+/// connections and a signal does not need the names of the modules.
+#[derive(Debug, Clone)]
+pub struct ModuleCollection {
+    // Which module sends its pulses to which modules. It also contains the button and
+    // the receivers that are no modules (e.g. "rx").
+    connections: Graph,
+    // The module of every node (None for the button and the receivers that are no
+    // modules).
+    modules: Vec<Option<ModuleType>>,
+    outputs: Vec<Vec<Output>>,
+    // The senders of every node (each once), so that the network can also be followed
+    // backwards.
+    inputs: Vec<Vec<NodeId>>,
+    button: NodeId,
+    broadcaster: NodeId,
+    number_of_presses: usize,
+    // The recorded signals if tracing is enabled.
+    trace: Option<Trace>,
+}
+
+impl ModuleCollection {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        let lines = parse_lines(reader, Module::from_line)?;
+        let number_of_lines = lines.len();
+        let mut connections = Graph::new_directed();
+        let mut module_types: Vec<(NodeId, ModuleType)> = Vec::new();
+        for (module, outputs) in lines {
+            let sender = connections.add_node(&module.name);
+            for output in outputs.iter() {
+                let receiver = connections.add_node(output);
+                connections.add_edge(sender, receiver);
+            }
+            module_types.push((sender, module.module_type));
+        }
+        let broadcaster = match connections.node_id("broadcaster") {
+            Some(broadcaster) if module_types.iter().any(|&(node, _)| node == broadcaster) => {
+                broadcaster
+            }
+            _ => {
+                return Err(
+                    ParseError::new(number_of_lines + 1, 1, "a broadcaster module")
+                        .found("end of input"),
+                )
+            }
+        };
+        let button = connections.add_node("button");
+        connections.add_edge(button, broadcaster);
+        // Now we also need to check what modules are inputs for
+        // a conjunction module (every sender counts once).
+        let mut inputs: Vec<Vec<NodeId>> = vec![Vec::new(); connections.node_count()];
+        for sender in connections.nodes() {
+            for receiver in connections.neighbors(sender) {
+                if inputs[receiver].last() != Some(&sender) {
+                    inputs[receiver].push(sender);
+                }
+            }
+        }
+        let mut modules: Vec<Option<ModuleType>> = vec![None; connections.node_count()];
+        for (node, mut module_type) in module_types {
+            if let ModuleType::Conjunction(memory) = &mut module_type {
+                *memory = ConjunctionMemory::new(inputs[node].len());
+            }
+            modules[node] = Some(module_type);
+        }
+        let outputs: Vec<Vec<Output>> = connections
+            .nodes()
+            .map(|sender| {
+                connections
+                    .neighbors(sender)
+                    .map(|receiver| Output {
+                        receiver,
+                        input: inputs[receiver]
+                            .iter()
+                            .position(|&input| input == sender)
+                            .unwrap(),
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            connections,
+            modules,
+            outputs,
+            inputs,
+            button,
+            broadcaster,
+            number_of_presses: 0,
+            trace: None,
+        })
+    }
+
+    /// Function that starts recording every signal (a running recording is continued).
+    pub fn enable_tracing(&mut self) {
+        self.trace.get_or_insert_with(Trace::default);
+    }
+
+    /// Function that stops recording and returns the signals recorded so far.
+    pub fn take_trace(&mut self) -> Option<Trace> {
+        self.trace.take()
+    }
+
+    /// Function that lets the receiver process the signal and queues the pulses it
+    /// sends.
+    fn process_signal(&mut self, signal: &Signal, signals_to_process: &mut VecDeque<Signal>) {
+        // Note that not all receivers must exist as a sender.
+        let signal_type = match &mut self.modules[signal.receiver] {
+            Some(module_type) => module_type.process_signal(signal),
+            None => None,
+        };
+        if let Some(signal_type) = signal_type {
+            signals_to_process.extend(self.outputs[signal.receiver].iter().map(|output| Signal {
+                sender: signal.receiver,
+                receiver: output.receiver,
+                input: output.input,
+                signal_type,
+            }));
+        }
+    }
+
+    /// Function that presses the button once and processes the signals until none is
+    /// left. Every signal is passed to the function before it is processed.
+    fn press_button_with<F: FnMut(&Signal)>(&mut self, mut on_signal: F) {
+        self.number_of_presses += 1;
+        // Send the first signal to the broadcaster.
+        let mut signals_to_process: VecDeque<Signal> = VecDeque::from([Signal {
+            sender: self.button,
+            receiver: self.broadcaster,
+            input: 0,
+            signal_type: SignalType::Low,
+        }]);
+        while let Some(signal) = signals_to_process.pop_front() {
+            on_signal(&signal);
+            if let Some(trace) = &mut self.trace {
+                trace.signals.push(TracedSignal {
+                    press: self.number_of_presses,
+                    sender: self.connections.name(signal.sender).to_string(),
+                    receiver: self.connections.name(signal.receiver).to_string(),
+                    signal_type: signal.signal_type,
+                });
+            }
+            self.process_signal(&signal, &mut signals_to_process);
+        }
+    }
+
+    pub fn press_button(&mut self) {
+        self.press_button_with(|_| ());
+    }
+
+    fn count_signals(&mut self, number_of_button_presses: usize) -> usize {
+        let mut number_of_low_signals = 0;
+        let mut number_of_high_signals = 0;
+        for _ in 0..number_of_button_presses {
+            self.press_button_with(|signal| match signal.signal_type {
+                SignalType::Low => number_of_low_signals += 1,
+                SignalType::High => number_of_high_signals += 1,
+            });
+        }
+        number_of_low_signals * number_of_high_signals
+    }
+
+    /// Function that returns the modules from which pulses can reach the module
+    /// (without the broadcaster, from which every pulse starts).
+    fn get_upstream_modules(&self, module: NodeId) -> HashSet<NodeId> {
+        let mut upstream_modules: HashSet<NodeId> = HashSet::new();
+        let mut modules_to_check: Vec<NodeId> = vec![module];
+        while let Some(module) = modules_to_check.pop() {
+            for &sender in self.inputs[module].iter() {
+                if sender != self.broadcaster && upstream_modules.insert(sender) {
+                    modules_to_check.push(sender);
+                }
+            }
+        }
+        upstream_modules
+    }
+
+    /// Function that checks if the module receives the pulse from a single conjunction
+    /// whose inputs are fed by separate parts of the network (sub-counters that repeat
+    /// independently of each other). Returns the name of the conjunction and its
+    /// inputs (sorted by name).
+    fn get_sub_counters(
+        &self,
+        module: NodeId,
+        pulse: &SignalType,
+    ) -> Result<(String, Vec<String>), ProbeError> {
+        let module_name = self.connections.name(module).to_string();
+        // A conjunction only sends a Low pulse when all of its inputs sent a High one.
+        if *pulse != SignalType::Low {
+            return Err(ProbeError::UnpredictablePulse {
+                module: module_name,
+                pulse: *pulse,
+            });
+        }
+        let senders = &self.inputs[module];
+        let conjunction = match senders[..] {
+            [sender] if matches!(self.modules[sender], Some(ModuleType::Conjunction(_))) => sender,
+            _ => {
+                return Err(ProbeError::NotFedByOneConjunction {
+                    module: module_name,
+                    senders: senders
+                        .iter()
+                        .map(|&sender| self.connections.name(sender).to_string())
+                        .collect(),
+                })
+            }
+        };
+        let mut inputs = self.inputs[conjunction].clone();
+        inputs.sort_by_key(|&input| self.connections.name(input));
+        let upstream_modules: Vec<HashSet<NodeId>> = inputs
+            .iter()
+            .map(|&input| {
+                let mut upstream_modules = self.get_upstream_modules(input);
+                upstream_modules.insert(input);
+                upstream_modules
+            })
+            .collect();
+        for idx_first in 0..inputs.len() {
+            for idx_second in idx_first + 1..inputs.len() {
+                if !upstream_modules[idx_first].is_disjoint(&upstream_modules[idx_second]) {
+                    return Err(ProbeError::SharedUpstream {
+                        first: self.connections.name(inputs[idx_first]).to_string(),
+                        second: self.connections.name(inputs[idx_second]).to_string(),
+                    });
+                }
+            }
+        }
+        Ok((
+            self.connections.name(conjunction).to_string(),
+            inputs
+                .iter()
+                .map(|&input| self.connections.name(input).to_string())
+                .collect(),
+        ))
+    }
+
+    /// Function that returns after how many button presses the module receives the
+    /// pulse for the first time. The network is simulated until that happens. If it
+    /// does not happen within the press limit, the module has to be fed by one
+    /// conjunction whose inputs are independent sub-counters: The presses at which
+    /// every input sent a High pulse to the conjunction during the simulation give
+    /// their cycles, and the first press at which all of them send it is calculated
+    /// from these cycles.
+    pub fn find_presses_until_pulse(
+        &self,
+        module: &str,
+        pulse: SignalType,
+    ) -> Result<usize, ProbeError> {
+        let node = self
+            .connections
+            .node_id(module)
+            .ok_or_else(|| ProbeError::UnknownModule(module.to_string()))?;
+        let sub_counters = self.get_sub_counters(node, &pulse);
+        // The conjunction and the presses at which every input sent a High pulse to it.
+        let (conjunction, mut presses_of_inputs): (Option<NodeId>, HashMap<NodeId, Vec<usize>>) =
+            match &sub_counters {
+                Ok((conjunction, inputs)) => (
+                    self.connections.node_id(conjunction),
+                    inputs
+                        .iter()
+                        .map(|input| (self.connections.node_id(input).unwrap(), Vec::new()))
+                        .collect(),
+                ),
+                Err(_) => (None, HashMap::new()),
+            };
+        let mut module_collection = self.clone();
+        for n_press in 1..=MAX_PRESSES {
+            let mut received = false;
+            module_collection.press_button_with(|signal| {
+                if signal.receiver == node && signal.signal_type == pulse {
+                    received = true;
+                }
+                if Some(signal.receiver) == conjunction && signal.signal_type == SignalType::High {
+                    let presses = presses_of_inputs.get_mut(&signal.sender).unwrap();
+                    // Three presses are enough to check that the cycle is regular.
+                    if presses.len() < 3 && presses.last() != Some(&n_press) {
+                        presses.push(n_press);
+                    }
+                }
+            });
+            if received {
+                return Ok(n_press);
+            }
+        }
+        let (_, inputs) = sub_counters?;
+        let mut cycles: Vec<Cycle> = Vec::new();
+        for input in inputs {
+            let presses = &presses_of_inputs[&self.connections.node_id(&input).unwrap()];
+            if presses.len() < 3 {
+                return Err(ProbeError::NoCycleFound {
+                    input,
+                    presses: MAX_PRESSES,
+                });
+            }
+            if presses[2] - presses[1] != presses[1] - presses[0] {
+                return Err(ProbeError::IrregularCycle {
+                    input,
+                    presses: [presses[0], presses[1], presses[2]],
+                });
+            }
+            cycles.push(Cycle {
+                input,
+                first_press: presses[0],
+                period: presses[1] - presses[0],
+            });
+        }
+        find_first_common_press(&cycles)
+    }
+}
+
+/// The presses at which an input of a conjunction sends a High pulse to it: the first
+/// one and then every period presses.
+#[derive(Debug)]
+struct Cycle {
+    input: String,
+    first_press: usize,
+    period: usize,
+}
+
+/// Function that returns (gcd(a, b), x) with a * x = gcd(a, b) (mod b).
+fn extended_gcd(a: i128, b: i128) -> (i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+    }
+    (old_r, old_x)
+}
+
+/// Function that finds the first press at which all cycles send their pulse (chinese
+/// remainder theorem for periods that do not need to be coprime). If every cycle
+/// starts with a whole period, this is the least common multiple of the periods.
+fn find_first_common_press(cycles: &[Cycle]) -> Result<usize, ProbeError> {
+    // All presses that are `remainder` modulo `period` fit the cycles so far.
+    let (mut remainder, mut period): (i128, i128) = (0, 1);
+    for cycle in cycles.iter() {
+        let cycle_period = cycle.period as i128;
+        let cycle_remainder = (cycle.first_press % cycle.period) as i128;
+        let (gcd, inverse) = extended_gcd(period, cycle_period);
+        let difference = cycle_remainder - remainder;
+        if difference % gcd != 0 {
+            return Err(ProbeError::NoCommonPress(cycle.input.clone()));
+        }
+        // Find k with remainder + k * period = cycle_remainder (mod cycle_period).
+        let modulus = cycle_period / gcd;
+        let k = (difference / gcd * inverse).rem_euclid(modulus);
+        remainder += k * period;
+        period *= modulus;
+    }
+    // Every cycle needs to have started.
+    let start = cycles
+        .iter()
+        .map(|cycle| cycle.first_press)
+        .max()
+        .unwrap_or(1) as i128;
+    if remainder < start {
+        remainder += (start - remainder + period - 1) / period * period;
+    }
+    Ok(remainder as usize)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = ModuleCollection;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        ModuleCollection::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(module_collection: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        module_collection.clone().count_signals(1000).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(
+        module_collection: &Self::Input,
+        _parameters: &Self::Parameters,
+    ) -> Answer {
+        match module_collection.find_presses_until_pulse("rx", SignalType::Low) {
+            Ok(presses) => presses.into(),
+            Err(error) => Answer::Unsolved(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, Cursor},
+    };
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Module::from_line(String::from("%a -> inv,")).unwrap_err(),
+            ParseError::new(1, 11, "a name").found("end of line")
+        );
+        assert_eq!(
+            Module::from_line(String::from("button -> broadcaster")).unwrap_err(),
+            ParseError::new(1, 1, "'%', '&' or 'broadcaster'").found("'b'")
+        );
+    }
+
+    #[test]
+    fn test_conjunction_memory() {
+        // More inputs than bits in one word.
+        let mut memory = ConjunctionMemory::new(70);
+        for input in 0..69 {
+            assert!(!memory.update(input, SignalType::High));
+        }
+        assert!(!memory.update(0, SignalType::High));
+        assert!(memory.update(69, SignalType::High));
+        assert!(!memory.update(65, SignalType::Low));
+        assert!(!memory.update(65, SignalType::Low));
+        assert!(memory.update(65, SignalType::High));
+    }
+
+    #[test]
+    fn test_find_presses_until_pulse() {
+        // Two sub-counters: "ia" sends a High pulse at the presses 3, 7, 11, ... and
+        // "ib" at the odd presses.
+        let network = "broadcaster -> a1, b1
+%a1 -> a2, ka
+%a2 -> ka
+&ka -> ia
+&ia -> s
+%b1 -> kb
+&kb -> ib
+&ib -> s
+&s -> rx";
+        let module_collection = ModuleCollection::from_reader(Cursor::new(network)).unwrap();
+        assert_eq!(
+            module_collection.find_presses_until_pulse("rx", SignalType::Low),
+            Ok(3)
+        );
+        // Pulses that are received within the press limit are found by the simulation,
+        // even if they could not be predicted from sub-counters.
+        assert_eq!(
+            module_collection.find_presses_until_pulse("rx", SignalType::High),
+            Ok(1)
+        );
+        assert_eq!(
+            module_collection.find_presses_until_pulse("ka", SignalType::Low),
+            Ok(2)
+        );
+        // The broadcaster only sends Low pulses.
+        assert_eq!(
+            module_collection.find_presses_until_pulse("a1", SignalType::High),
+            Err(ProbeError::UnpredictablePulse {
+                module: String::from("a1"),
+                pulse: SignalType::High
+            })
+        );
+        assert_eq!(
+            module_collection.find_presses_until_pulse("zz", SignalType::Low),
+            Err(ProbeError::UnknownModule(String::from("zz")))
+        );
+        let node = |name: &str| module_collection.connections.node_id(name).unwrap();
+        assert_eq!(
+            module_collection.get_sub_counters(node("rx"), &SignalType::Low),
+            Ok((
+                String::from("s"),
+                vec![String::from("ia"), String::from("ib")]
+            ))
+        );
+        assert_eq!(
+            module_collection.get_sub_counters(node("ka"), &SignalType::Low),
+            Err(ProbeError::NotFedByOneConjunction {
+                module: String::from("ka"),
+                senders: vec![String::from("a1"), String::from("a2")]
+            })
+        );
+        // When "a2" also counts for "b1", the sub-counters are not independent.
+        let network = "broadcaster -> a1, b1
+%a1 -> a2, ka
+%a2 -> ka, kb
+&ka -> s
+%b1 -> kb
+&kb -> s
+&s -> rx";
+        let module_collection = ModuleCollection::from_reader(Cursor::new(network)).unwrap();
+        let rx = module_collection.connections.node_id("rx").unwrap();
+        assert_eq!(
+            module_collection.get_sub_counters(rx, &SignalType::Low),
+            Err(ProbeError::SharedUpstream {
+                first: String::from("ka"),
+                second: String::from("kb")
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_first_common_press() {
+        let cycle = |first_press: usize, period: usize| Cycle {
+            input: String::new(),
+            first_press,
+            period,
+        };
+        // The least common multiple, not the product.
+        assert_eq!(find_first_common_press(&[cycle(4, 4), cycle(6, 6)]), Ok(12));
+        assert_eq!(find_first_common_press(&[cycle(3, 4), cycle(2, 5)]), Ok(7));
+        assert_eq!(
+            find_first_common_press(&[cycle(11, 4), cycle(1, 2)]),
+            Ok(11)
+        );
+        assert!(find_first_common_press(&[cycle(3, 4), cycle(2, 2)]).is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        let reader = BufReader::new(File::open("./input2.test").expect("Input file not found."));
+        let mut module_collection = Day20::parse(reader).unwrap();
+        module_collection.press_button();
+        module_collection.enable_tracing();
+        module_collection.press_button();
+        let trace = module_collection.take_trace().unwrap();
+        // The second press flips "a" off, so "inv" sends a High pulse to "b".
+        assert_eq!(trace.signals.len(), 6);
+        assert!(trace.signals.iter().all(|signal| signal.press == 2));
+        assert_eq!(
+            trace.signals[4],
+            TracedSignal {
+                press: 2,
+                sender: String::from("inv"),
+                receiver: String::from("b"),
+                signal_type: SignalType::High,
+            }
+        );
+        assert_eq!(module_collection.take_trace(), None);
+    }
+
+    #[test]
+    fn test_first_task_first_example() {
+        let reader = BufReader::new(File::open("./input1.test").expect("Input file not found."));
+        assert_eq!(
+            Day20::solve_first_task(&Day20::parse(reader).unwrap(), &()),
+            Answer::Unsigned(32000000)
+        );
+    }
+
+    #[test]
+    fn test_find_presses_until_pulse_second_example() {
+        let reader = BufReader::new(File::open("./input2.test").expect("Input file not found."));
+        let module_collection = ModuleCollection::from_reader(reader).unwrap();
+        // "inv" sends a High pulse to "b" when "a" is turned off by the second press.
+        assert_eq!(
+            module_collection.find_presses_until_pulse("b", SignalType::High),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn test_first_task_second_example() {
+        let reader = BufReader::new(File::open("./input2.test").expect("Input file not found."));
+        assert_eq!(
+            Day20::solve_first_task(&Day20::parse(reader).unwrap(), &()),
+            Answer::Unsigned(11687500)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::SignalType;
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+/// One recorded signal: During which button press which module sent which pulse to
+/// which module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedSignal {
+    pub press: usize,
+    pub sender: String,
+    pub receiver: String,
+    pub signal_type: SignalType,
+}
+
+/// All signals in the order in which they were processed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub signals: Vec<TracedSignal>,
+}
+
+/// Function that returns a short identifier for a variable of a VCD file (made from
+/// the printable ASCII characters).
+fn get_vcd_identifier(mut idx: usize) -> String {
+    let mut identifier = String::new();
+    loop {
+        identifier.push((b'!' + (idx % 94) as u8) as char);
+        idx /= 94;
+        if idx == 0 {
+            return identifier;
+        }
+    }
+}
+
+fn get_level(signal_type: &SignalType) -> char {
+    match signal_type {
+        SignalType::Low => '0',
+        SignalType::High => '1',
+    }
+}
+
+impl Trace {
+    /// Function that writes the signals as CSV with one line per signal, e.g.
+    /// "1,broadcaster,a,low".
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "press,sender,receiver,pulse")?;
+        for signal in self.signals.iter() {
+            let pulse = match signal.signal_type {
+                SignalType::Low => "low",
+                SignalType::High => "high",
+            };
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                signal.press, signal.sender, signal.receiver, pulse
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Function that writes the signals as VCD waveform (value change dump). Every
+    /// signal is one time step. There is one wire per sending module that shows the
+    /// level of the last pulse it sent (all start Low) and a counter of the button
+    /// presses.
+    pub fn write_vcd<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut senders: Vec<&str> = self
+            .signals
+            .iter()
+            .map(|signal| signal.sender.as_str())
+            .collect();
+        senders.sort();
+        senders.dedup();
+        let press_identifier = get_vcd_identifier(0);
+        let identifiers: HashMap<&str, String> = senders
+            .iter()
+            .enumerate()
+            .map(|(idx, &sender)| (sender, get_vcd_identifier(idx + 1)))
+            .collect();
+        writeln!(writer, "$timescale 1ns $end")?;
+        writeln!(writer, "$scope module network $end")?;
+        writeln!(writer, "$var integer 32 {} press $end", press_identifier)?;
+        for sender in senders.iter() {
+            writeln!(
+                writer,
+                "$var wire 1 {} {} $end",
+                identifiers[sender], sender
+            )?;
+        }
+        writeln!(writer, "$upscope $end")?;
+        writeln!(writer, "$enddefinitions $end")?;
+        writeln!(writer, "#0")?;
+        writeln!(writer, "$dumpvars")?;
+        writeln!(writer, "b0 {}", press_identifier)?;
+        for sender in senders.iter() {
+            writeln!(writer, "0{}", identifiers[sender])?;
+        }
+        writeln!(writer, "$end")?;
+        let mut levels: HashMap<&str, char> = senders.iter().map(|&sender| (sender, '0')).collect();
+        let mut press = 0;
+        for (idx, signal) in self.signals.iter().enumerate() {
+            let mut changes: Vec<String> = Vec::new();
+            if signal.press != press {
+                press = signal.press;
+                changes.push(format!("b{:b} {}", press, press_identifier));
+            }
+            let level = get_level(&signal.signal_type);
+            let last_level = levels.get_mut(signal.sender.as_str()).unwrap();
+            if *last_level != level {
+                *last_level = level;
+                changes.push(format!("{}{}", level, identifiers[signal.sender.as_str()]));
+            }
+            if !changes.is_empty() {
+                writeln!(writer, "#{}", idx + 1)?;
+                for change in changes {
+                    writeln!(writer, "{}", change)?;
+                }
+            }
+        }
+        writeln!(writer, "#{}", self.signals.len() + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Trace {
+        let signal =
+            |press: usize, sender: &str, receiver: &str, signal_type: SignalType| TracedSignal {
+                press,
+                sender: sender.to_string(),
+                receiver: receiver.to_string(),
+                signal_type,
+            };
+        Trace {
+            signals: vec![
+                signal(1, "button", "broadcaster", SignalType::Low),
+                signal(1, "broadcaster", "a", SignalType::Low),
+                signal(1, "a", "b", SignalType::High),
+                signal(2, "button", "broadcaster", SignalType::Low),
+                signal(2, "broadcaster", "a", SignalType::Low),
+                signal(2, "a", "b", SignalType::Low),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut csv: Vec<u8> = Vec::new();
+        example().write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv)
+                .unwrap()
+                .lines()
+                .collect::<Vec<&str>>(),
+            vec![
+                "press,sender,receiver,pulse",
+                "1,button,broadcaster,low",
+                "1,broadcaster,a,low",
+                "1,a,b,high",
+                "2,button,broadcaster,low",
+                "2,broadcaster,a,low",
+                "2,a,b,low",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_vcd() {
+        let mut vcd: Vec<u8> = Vec::new();
+        example().write_vcd(&mut vcd).unwrap();
+        let vcd = String::from_utf8(vcd).unwrap();
+        // The senders are sorted by name: a ("), broadcaster (#) and button ($).
+        assert!(vcd.contains("$var integer 32 ! press $end\n$var wire 1 \" a $end\n"));
+        assert!(vcd.ends_with("$end\n#1\nb1 !\n#3\n1\"\n#4\nb10 !\n#6\n0\"\n#7\n"));
+        assert_eq!(get_vcd_identifier(93), "~");
+        assert_eq!(get_vcd_identifier(94), "!\"");
+    }
+}
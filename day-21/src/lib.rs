@@ -0,0 +1,278 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Position};
+use std::{collections::HashSet, io::BufRead};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+struct MapLocation {
+    pos: Position,
+    map_id: (isize, isize),
+}
+
+#[derive(Debug)]
+pub struct Map {
+    map: Grid<u8>, // '.' is reachable, '#' is not reachable.
+    loc_start: MapLocation,
+    number_of_obstacles_of_the_start: usize,
+}
+
+impl Map {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        let mut map: Grid<u8> = Grid::from_reader(reader, "'.', '#' or 'S'", |c| {
+            ".#S".contains(c).then_some(c as u8)
+        })?;
+        // Find the start position.
+        let pos_start = match map.find(|&byte| byte == b'S') {
+            Some(pos_start) => pos_start,
+            None => {
+                return Err(ParseError::new(
+                    map.height(),
+                    map.width() + 1,
+                    "the start position 'S'",
+                )
+                .found("end of input"))
+            }
+        };
+        // Change the value of the start position within the map from b'S' to b'.'.
+        map[pos_start] = b'.';
+        // Find the number of obstacles that lay in the way of the starting position.
+        // This only means vertical and horizontal. The efficient algorithm needs to
+        // be able to cast straight lines from the starting position to all four directions.
+        let number_of_obstacles_of_the_start = map
+            .row(pos_start.y)
+            .iter()
+            .chain(map.column(pos_start.x))
+            .filter(|&&b| b == b'#')
+            .count();
+        Ok(Self {
+            map,
+            loc_start: MapLocation {
+                pos: pos_start,
+                map_id: (0, 0),
+            },
+            number_of_obstacles_of_the_start,
+        })
+    }
+
+    fn get_neighbors(
+        &self,
+        loc: MapLocation,
+        repeat_map: bool,
+    ) -> impl Iterator<Item = MapLocation> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| {
+            let neighbor = match self.map.neighbor(loc.pos, direction) {
+                // We stay on the same map_id.
+                Some(pos) => MapLocation {
+                    pos,
+                    map_id: loc.map_id,
+                },
+                // Wrap around to the next map into this direction.
+                None if repeat_map => {
+                    let (dx, dy) = direction.offset();
+                    MapLocation {
+                        pos: self.map.neighbor_wrapping(loc.pos, direction),
+                        map_id: (loc.map_id.0 + dx, loc.map_id.1 + dy),
+                    }
+                }
+                None => return None,
+            };
+            (self.map[neighbor.pos] == b'.').then_some(neighbor)
+        })
+    }
+
+    fn get_number_of_possible_positions(&self, number_of_steps: usize, repeat_map: bool) -> usize {
+        // If we repeat the map and want to take steps smaller than the 2.5x size of
+        // the map, we simply bruteforce. Otherwise we bruteforce until we have 2.5x
+        // size of the map and then we extrapolate.
+        // Helper variables for this:
+        let mut lookup: [usize; 3] = [0; 3];
+        let mut max_steps_before_interpolation =
+            ((self.map.width() - 1) / 2) + 2 * self.map.width();
+        // This solution also only works if the number of steps results in full outer maps.
+        if number_of_steps < max_steps_before_interpolation {
+            // In this case the interpolation cannot be applied but on the other hand would not be faster since we would need at least max_steps_before_interpolation.
+        } else if !(number_of_steps - ((self.map.width() - 1) / 2)).is_multiple_of(self.map.width())
+        {
+            eprintln!("WARNING: These amount of steps can currently only be bruteforced.");
+            eprintln!("To use a more efficient algorithm, set the number of steps to (width - 1) + n * width for all n.");
+            max_steps_before_interpolation = number_of_steps;
+        } else if self.map.width() != self.map.height() {
+            eprintln!("WARNING: The efficient algorithm only works for square maps nxn. This map has the size: {}x{}.", self.map.width(), self.map.height());
+            max_steps_before_interpolation = number_of_steps;
+        } else if self.number_of_obstacles_of_the_start != 0 {
+            eprintln!("WARNING: The efficient algorithm only works if there a no obstacles to the top, right, bottom and left of the start position. It needs to cast straight lines in these four directions.");
+            max_steps_before_interpolation = number_of_steps;
+        } else if !repeat_map {
+            // The algorithm only makes sense when the map repeats itself.
+            max_steps_before_interpolation = number_of_steps;
+        }
+        // Keeping all possible locations for every step gets slow very fast, so we do a
+        // breadth first search instead and only keep the locations we reached for the
+        // first time within the last two steps. A location first reached after n steps
+        // can also be reached after n + 2, n + 4, ... steps by walking back and forth.
+        // So the number of possible locations is the number of locations first reached
+        // after an even (or odd) number of steps.
+        let mut number_of_possible_locations: [usize; 2] = [1, 0];
+        let mut previous_locations: HashSet<MapLocation> = HashSet::new();
+        // Start at the starting position and then take the number of steps.
+        let mut current_locations: HashSet<MapLocation> = HashSet::from([self.loc_start]);
+        for step_count in 1..=number_of_steps.min(max_steps_before_interpolation) {
+            // Since we can only move up, down, left or right, a new location can not be
+            // one of the current locations, only one of the previous locations.
+            let next_locations: HashSet<MapLocation> = current_locations
+                .iter()
+                .flat_map(|&loc| self.get_neighbors(loc, repeat_map))
+                .filter(|loc| !previous_locations.contains(loc))
+                .collect();
+            number_of_possible_locations[step_count % 2] += next_locations.len();
+            previous_locations = current_locations;
+            current_locations = next_locations;
+            // Maybe we need to save this.
+            let possible_locations = number_of_possible_locations[step_count % 2];
+            if step_count == (self.map.width() - 1) / 2 {
+                lookup[0] = possible_locations;
+            } else if step_count == ((self.map.width() - 1) / 2) + self.map.width() {
+                lookup[1] = possible_locations;
+            } else if step_count == ((self.map.width() - 1) / 2) + 2 * self.map.width() {
+                lookup[2] = possible_locations;
+            }
+        }
+        if number_of_steps <= max_steps_before_interpolation {
+            number_of_possible_locations[number_of_steps % 2]
+        } else {
+            let diff_lookup: [usize; 3] = [lookup[0], lookup[1] - lookup[0], lookup[2] - lookup[1]];
+            // We want to interpolate the whole map and not the steps.
+            let factor = number_of_steps / self.map.width();
+            // Quadratic polynomial interpolation.
+            // f(n) = b0 + b1 * n + (n * (n − 1)) / 2​* (b2 − b1)
+            diff_lookup[0]
+                + diff_lookup[1] * factor
+                + (factor * (factor - 1) / 2) * (diff_lookup[2] - diff_lookup[1])
+        }
+    }
+}
+
+fn solve_task(map: &Map, number_of_steps: usize, repeat_map: bool) -> usize {
+    map.get_number_of_possible_positions(number_of_steps, repeat_map)
+}
+
+pub struct Parameters {
+    /// The number of steps the elf takes within the first task.
+    pub number_of_steps_first_task: usize,
+    /// The number of steps the elf takes on the infinite map within the second task.
+    pub number_of_steps_second_task: usize,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            number_of_steps_first_task: 64,
+            number_of_steps_second_task: 26501365,
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Map;
+    type Parameters = Parameters;
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Map::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(map: &Self::Input, parameters: &Self::Parameters) -> Answer {
+        solve_task(map, parameters.number_of_steps_first_task, false).into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(map: &Self::Input, parameters: &Self::Parameters) -> Answer {
+        solve_task(map, parameters.number_of_steps_second_task, true).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day21::parse(reader).unwrap(), 6, false), 16);
+    }
+
+    #[test]
+    fn test_breadth_first_search() {
+        // The breadth first search only keeps the last two steps, so compare it with
+        // keeping all possible locations of every step.
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        let map = Day21::parse(reader).unwrap();
+        for repeat_map in [false, true] {
+            let mut possible_locations: HashSet<MapLocation> = HashSet::from([map.loc_start]);
+            // Below 2.5x the size of the map, the number of positions is not interpolated.
+            for number_of_steps in 0..(map.map.width() - 1) / 2 + 2 * map.map.width() {
+                assert_eq!(
+                    map.get_number_of_possible_positions(number_of_steps, repeat_map),
+                    possible_locations.len()
+                );
+                possible_locations = possible_locations
+                    .iter()
+                    .flat_map(|&loc| map.get_neighbors(loc, repeat_map))
+                    .collect();
+            }
+        }
+    }
+
+    #[test]
+    fn test_second_task_6_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day21::parse(reader).unwrap(), 6, true), 16);
+    }
+
+    #[test]
+    fn test_second_task_10_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day21::parse(reader).unwrap(), 10, true), 50);
+    }
+
+    #[test]
+    fn test_second_task_50_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day21::parse(reader).unwrap(), 50, true), 1594);
+    }
+
+    #[test]
+    fn test_second_task_100_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(solve_task(&Day21::parse(reader).unwrap(), 100, true), 6536);
+    }
+
+    #[test]
+    fn test_second_task_500_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            solve_task(&Day21::parse(reader).unwrap(), 500, true),
+            167004
+        );
+    }
+
+    #[test]
+    fn test_second_task_1000_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            solve_task(&Day21::parse(reader).unwrap(), 1000, true),
+            668697
+        );
+    }
+
+    #[test]
+    fn test_second_task_5000_steps() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            solve_task(&Day21::parse(reader).unwrap(), 5000, true),
+            16733044
+        );
+    }
+}
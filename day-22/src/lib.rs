@@ -1,25 +1,21 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
 use std::{
     collections::{BTreeSet, HashMap, HashSet, VecDeque},
-    fs::File,
-    io::{BufRead, BufReader},
+    io::BufRead,
 };
 
-#[derive(Debug, Default)]
-enum Task {
-    #[default]
-    First,
-    Second,
-}
+// The two opposite corners (x, y, z) of a brick.
+type BrickCorners = ((usize, usize, usize), (usize, usize, usize));
 
 #[derive(Debug)]
-struct Bricks {
+pub struct Bricks {
     brick_supports: HashMap<usize, HashSet<usize>>,
     brick_is_supported_by: HashMap<usize, HashSet<usize>>,
     bricks_z_location: BTreeSet<(usize, usize)>,
 }
 
 impl Bricks {
-    fn from_reader<B: BufRead>(reader: B) -> Self {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
         let mut stack: HashMap<(usize, usize), Vec<Option<usize>>> = HashMap::new();
         let mut brick_supports: HashMap<usize, HashSet<usize>> = HashMap::new();
         let mut brick_is_supported_by: HashMap<usize, HashSet<usize>> = HashMap::new();
@@ -27,18 +23,16 @@ impl Bricks {
         // The test input works directly with this approach but the real input
         // is not as sorted as the test input. So we will first read in the input,
         // sort it by the z value and then apply the algorithm.
-        let ordered_bricks: BTreeSet<(usize, ((usize, usize, usize), (usize, usize, usize)))> =
-            reader
-                .lines()
-                .map(Result::unwrap)
-                .map(Bricks::parse_line)
+        let ordered_bricks: BTreeSet<(usize, BrickCorners)> =
+            parse_lines(reader, Bricks::parse_line)?
+                .into_iter()
                 .collect();
         for (brick_id, (_z_min, (pos1, pos2))) in ordered_bricks.iter().enumerate() {
             // Get the height of all the piles for each (x, y) position.
             let mut max_height = 0;
             for x in pos1.0.min(pos2.0)..=pos1.0.max(pos2.0) {
                 for y in pos1.1.min(pos2.1)..=pos1.1.max(pos2.1) {
-                    let pile = stack.entry((x, y)).or_insert(Vec::new());
+                    let pile = stack.entry((x, y)).or_default();
                     max_height = max_height.max(pile.len());
                 }
             }
@@ -47,14 +41,13 @@ impl Bricks {
             // Save the min z value of this brick together with its id.
             bricks_z_location.insert((max_height + 1, brick_id));
             // For this brick we may need to insert some bricks that support this brick.
-            let supporter_bricks_of_current_brick = brick_is_supported_by
-                .entry(brick_id)
-                .or_insert(HashSet::new());
+            let supporter_bricks_of_current_brick =
+                brick_is_supported_by.entry(brick_id).or_default();
             // Even if this brick is not supporting anything, we need its entry.
             brick_supports.insert(brick_id, HashSet::new());
             for x in pos1.0.min(pos2.0)..=pos1.0.max(pos2.0) {
                 for y in pos1.1.min(pos2.1)..=pos1.1.max(pos2.1) {
-                    let pile = stack.entry((x, y)).or_insert(Vec::new());
+                    let pile = stack.entry((x, y)).or_default();
                     // Fill the pile until the desired height is reached with air.
                     if pile.len() < max_height {
                         pile.resize_with(max_height, || None);
@@ -68,9 +61,8 @@ impl Bricks {
                         if let Some(brick_below_id) = pile.get(max_height - 1).unwrap() {
                             // The brick below supports this brick.
                             // So insert this brick into the list of the brick below.
-                            let supported_bricks_of_brick_below = brick_supports
-                                .entry(*brick_below_id)
-                                .or_insert(HashSet::new());
+                            let supported_bricks_of_brick_below =
+                                brick_supports.entry(*brick_below_id).or_default();
                             supported_bricks_of_brick_below.insert(brick_id);
                             // This brick is supported by the brick below.
                             // So insert the brick below in this bricks list.
@@ -80,39 +72,33 @@ impl Bricks {
                 }
             }
         }
-        Self {
+        Ok(Self {
             brick_supports,
             brick_is_supported_by,
             bricks_z_location,
-        }
+        })
     }
 
-    fn parse_line(line: String) -> (usize, ((usize, usize, usize), (usize, usize, usize))) {
+    fn parse_line(line: String) -> Result<(usize, BrickCorners), ParseError> {
         // Example line: 1,0,1~1,2,1
         // Returns this coordinates as well as the min z value for sorting.
-        let (pos1, pos2) = line.split_once('~').unwrap();
-        let pos1: Vec<usize> = pos1
-            .split(',')
-            .into_iter()
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect();
-        let pos2: Vec<usize> = pos2
-            .split(',')
-            .into_iter()
-            .map(|s| s.parse::<usize>().unwrap())
-            .collect();
-        let pos1 = (
-            pos1.get(0).unwrap().clone(),
-            pos1.get(1).unwrap().clone(),
-            pos1.get(2).unwrap().clone(),
-        );
-        let pos2 = (
-            pos2.get(0).unwrap().clone(),
-            pos2.get(1).unwrap().clone(),
-            pos2.get(2).unwrap().clone(),
-        );
+        let mut parser = LineParser::new(&line);
+        let pos1 = Bricks::parse_position(&mut parser)?;
+        parser.expect("~")?;
+        let pos2 = Bricks::parse_position(&mut parser)?;
+        parser.end()?;
         // z-min, pos1, pos2.
-        (pos1.2.min(pos2.2), (pos1, pos2))
+        Ok((pos1.2.min(pos2.2), (pos1, pos2)))
+    }
+
+    /// Function that parses one corner of a brick (x,y,z).
+    fn parse_position(parser: &mut LineParser) -> Result<(usize, usize, usize), ParseError> {
+        let x = parser.number()?;
+        parser.expect(",")?;
+        let y = parser.number()?;
+        parser.expect(",")?;
+        let z = parser.number()?;
+        Ok((x, y, z))
     }
 
     fn get_number_of_bricks_safe_to_disintegrate(&self) -> usize {
@@ -129,9 +115,9 @@ impl Bricks {
                 // which supports it. If so, we could disintegrate our current brick.
                 let brick_is_supported_by =
                     self.brick_is_supported_by.get(supported_brick).unwrap();
-                if brick_is_supported_by.len() == 0 {
+                if brick_is_supported_by.is_empty() {
                     panic!("There should be atleast one brick supporting this brick.");
-                } else if brick_is_supported_by.contains(brick_id) == false {
+                } else if !brick_is_supported_by.contains(brick_id) {
                     panic!("This brick should be supported by our current brick.");
                 } else if brick_is_supported_by.len() == 1 {
                     // This brick is only supported by our current brick, so we cannot
@@ -139,7 +125,7 @@ impl Bricks {
                     can_be_disintegrated = false;
                 }
             }
-            if can_be_disintegrated == true {
+            if can_be_disintegrated {
                 safe_to_disintegrate_count += 1;
             }
         }
@@ -170,7 +156,7 @@ impl Bricks {
                     break;
                 }
             }
-            if will_fall == true {
+            if will_fall {
                 bricks_to_consider.extend(
                     self.brick_supports
                         .get(&current_brick)
@@ -192,70 +178,72 @@ impl Bricks {
     }
 }
 
-fn solve_first_task<B: BufRead>(reader: B) -> usize {
-    let bricks = Bricks::from_reader(reader);
-    bricks.get_number_of_bricks_safe_to_disintegrate()
-}
+pub struct Day22;
 
-fn solve_second_task<B: BufRead>(reader: B) -> usize {
-    let bricks = Bricks::from_reader(reader);
-    bricks.count_total_chain_reaction()
-}
+impl Solution for Day22 {
+    type Input = Bricks;
+    type Parameters = ();
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Bricks::from_reader(reader)
+    }
 
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        match task {
-            Task::First => solve_first_task(reader),
-            Task::Second => solve_second_task(reader),
-        }
-    )
+    /// Function that solves the first task.
+    fn solve_first_task(bricks: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        bricks.get_number_of_bricks_safe_to_disintegrate().into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(bricks: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        bricks.count_total_chain_reaction().into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_line_parsing() {
         assert_eq!(
-            Bricks::parse_line(String::from("1,1,1~10,10,10")),
+            Bricks::parse_line(String::from("1,1,1~10,10,10")).unwrap(),
             (1, ((1, 1, 1), (10, 10, 10)))
         );
         assert_eq!(
-            Bricks::parse_line(String::from("1,1,100~10,10,10")),
+            Bricks::parse_line(String::from("1,1,100~10,10,10")).unwrap(),
             (10, ((1, 1, 100), (10, 10, 10)))
         );
+        assert_eq!(
+            Bricks::parse_line(String::from("1,1,1-10,10,10")).unwrap_err(),
+            ParseError::new(1, 6, "'~'").found("'-'")
+        );
     }
 
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader), 5);
+        assert_eq!(
+            Day22::solve_first_task(&Day22::parse(reader).unwrap(), &()),
+            Answer::Unsigned(5)
+        );
     }
 
     #[test]
     fn test_first_task_own_input() {
         let reader = BufReader::new(File::open("./input_own.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader), 3);
+        assert_eq!(
+            Day22::solve_first_task(&Day22::parse(reader).unwrap(), &()),
+            Answer::Unsigned(3)
+        );
     }
 
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_second_task(reader), 7);
+        assert_eq!(
+            Day22::solve_second_task(&Day22::parse(reader).unwrap(), &()),
+            Answer::Unsigned(7)
+        );
     }
 }
@@ -0,0 +1,69 @@
+//! Prints the longest hikes of a day 23 input (down the slopes only and ignoring the
+//! slopes) on the map and optionally draws both into one SVG or PNG picture, e.g.:
+//!
+//! cargo run --release -p day-23 --example hike -- day-23/input hikes.svg
+use common::Solution;
+use day_23::Day23;
+use std::{
+    env,
+    fs::File,
+    io::{BufReader, BufWriter},
+    process::exit,
+};
+
+/// The number of pixels per tile in the PNG picture.
+const PNG_SCALE: usize = 4;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (input, output) = match &args[..] {
+        [input] => (input, None),
+        [input, output] if output.ends_with(".svg") || output.ends_with(".png") => {
+            (input, Some(output))
+        }
+        _ => {
+            eprintln!("Usage: hike <input> [<out.svg|out.png>]");
+            exit(2);
+        }
+    };
+    let reader = BufReader::new(File::open(input).expect("Input file not found."));
+    let hiking_trail = Day23::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(input.clone()));
+        exit(1);
+    });
+    let mut hikes = Vec::new();
+    for (ignore_slopes, title) in [(false, "Down the slopes"), (true, "Ignoring the slopes")] {
+        match hiking_trail.find_longest_hike(ignore_slopes) {
+            Some(hike) => {
+                println!("{}:\n{}\n", title, hiking_trail.render_hike(&hike));
+                hikes.push(hike);
+            }
+            None => println!("{}: There is no hike from the start to the end.\n", title),
+        }
+    }
+    let Some(output) = output else {
+        return;
+    };
+    let hikes: Vec<_> = hikes.iter().collect();
+    let writer = BufWriter::new(File::create(output).expect("Could not create the picture."));
+    if output.ends_with(".svg") {
+        hiking_trail
+            .write_svg(&hikes, writer)
+            .expect("Could not write the picture.");
+    } else {
+        let (width, height, pixels) = hiking_trail.render_pixels(&hikes, PNG_SCALE);
+        let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut png_writer = encoder
+            .write_header()
+            .expect("Could not write the picture.");
+        png_writer
+            .write_image_data(&pixels)
+            .expect("Could not write the picture.");
+    }
+    println!(
+        "The hikes were drawn to {} (first red, second blue).",
+        output
+    );
+}
@@ -0,0 +1,279 @@
+use common::{Answer, ParseError, Solution};
+use graph::Graph;
+use grid::{Direction, Grid, Position};
+use std::io::BufRead;
+
+mod render;
+
+/// A hike from the start to the end: the junctions it passes (including the start and
+/// the end) and the tiles of the corridors between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hike {
+    pub length: u64,
+    pub junctions: Vec<Position>,
+    /// The tiles from one junction to the next (without the first junction, with the
+    /// next one), so the length of a segment is the number of its tiles.
+    pub segments: Vec<Vec<Position>>,
+}
+
+#[derive(Debug)]
+pub struct HikingTrail {
+    map: Grid<char>,
+    pos_start: Position,
+    pos_end: Position,
+}
+
+impl HikingTrail {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        let map: Grid<char> =
+            Grid::from_reader(reader, "'#', '.' or a slope ('<', '>', '^', 'v')", |c| {
+                "#.<>^v".contains(c).then_some(c)
+            })?;
+        // The hike starts at the first gap in the border (from the top left) and ends at
+        // the other one.
+        let gaps: Vec<Position> = map
+            .positions()
+            .filter(|&pos| {
+                map[pos] != '#'
+                    && (pos.x == 0
+                        || pos.y == 0
+                        || pos.x == map.width() - 1
+                        || pos.y == map.height() - 1)
+            })
+            .collect();
+        let (pos_start, pos_end) = match gaps[..] {
+            [pos_start, pos_end] => (pos_start, pos_end),
+            [_, _, pos, ..] => {
+                return Err(ParseError::new(
+                    pos.y + 1,
+                    pos.x + 1,
+                    "'#' (only two gaps in the border)",
+                )
+                .found(format!("'{}'", map[pos])))
+            }
+            _ => {
+                return Err(ParseError::new(1, 1, "two gaps in the border")
+                    .found(format!("{}", gaps.len())))
+            }
+        };
+        Ok(Self {
+            map,
+            pos_start,
+            pos_end,
+        })
+    }
+
+    fn node_name(pos: Position) -> String {
+        format!("{},{}", pos.x, pos.y)
+    }
+
+    /// Function that checks if the position is a junction, i.e. the start, the end or a
+    /// crossroad with more than two possible ways to go / to come from.
+    fn is_junction(&self, pos: Position) -> bool {
+        pos == self.pos_start
+            || pos == self.pos_end
+            || self
+                .map
+                .neighbors4(pos)
+                .filter(|&neighbor| self.map[neighbor] != '#')
+                .count()
+                > 2
+    }
+
+    /// Function that returns the position one step into the direction if we are allowed
+    /// to go there. Every tile except the forest can be stepped onto, but from a slope
+    /// the next step has to go down the slope unless we ignore the slopes.
+    fn step(&self, pos: Position, direction: Direction, ignore_slopes: bool) -> Option<Position> {
+        if !ignore_slopes
+            && Direction::from_char(self.map[pos]).is_some_and(|slope| slope != direction)
+        {
+            return None;
+        }
+        let next = self.map.neighbor(pos, direction)?;
+        (self.map[next] != '#').then_some(next)
+    }
+
+    /// Function that follows the corridor that starts at the junction into the
+    /// direction until the next junction. Returns the tiles of the corridor (with the
+    /// next junction) or None if there is a dead end or we are not allowed to go there.
+    fn follow_corridor(
+        &self,
+        junction: Position,
+        first_direction: Direction,
+        ignore_slopes: bool,
+    ) -> Option<Vec<Position>> {
+        let mut direction = first_direction;
+        let mut tiles: Vec<Position> = vec![self.step(junction, direction, ignore_slopes)?];
+        while !self.is_junction(*tiles.last().unwrap()) {
+            let pos = *tiles.last().unwrap();
+            let (next, new_direction) = Direction::ALL
+                .into_iter()
+                .filter(|&new_direction| new_direction != direction.reverse())
+                .find_map(|new_direction| {
+                    self.step(pos, new_direction, ignore_slopes)
+                        .map(|next| (next, new_direction))
+                })?;
+            tiles.push(next);
+            direction = new_direction;
+        }
+        Some(tiles)
+    }
+
+    /// Function that compresses the map into a directed graph of the junctions. An edge
+    /// is a corridor between two junctions and its weight is the number of steps. The
+    /// positions of the junctions are returned as well (by their node ID).
+    fn create_junction_graph(&self, ignore_slopes: bool) -> (Graph, Vec<Position>) {
+        let mut graph = Graph::new_directed();
+        let junctions: Vec<Position> = self
+            .map
+            .positions()
+            .filter(|&pos| self.map[pos] != '#' && self.is_junction(pos))
+            .collect();
+        for &junction in junctions.iter() {
+            graph.add_node(&HikingTrail::node_name(junction));
+        }
+        for (from, &junction) in junctions.iter().enumerate() {
+            for direction in Direction::ALL {
+                if let Some(tiles) = self.follow_corridor(junction, direction, ignore_slopes) {
+                    let to = graph
+                        .node_id(&HikingTrail::node_name(*tiles.last().unwrap()))
+                        .unwrap();
+                    graph.add_weighted_edge(from, to, tiles.len() as u64);
+                }
+            }
+        }
+        (graph, junctions)
+    }
+
+    /// Function that finds the longest hike from the start to the end that does not
+    /// step onto a tile twice (None if the end can not be reached).
+    pub fn find_longest_hike(&self, ignore_slopes: bool) -> Option<Hike> {
+        let (graph, junctions) = self.create_junction_graph(ignore_slopes);
+        let start = graph
+            .node_id(&HikingTrail::node_name(self.pos_start))
+            .unwrap();
+        let end = graph
+            .node_id(&HikingTrail::node_name(self.pos_end))
+            .unwrap();
+        let (length, path) = graph.longest_simple_path(start, end, true)?;
+        let junctions: Vec<Position> = path.into_iter().map(|node| junctions[node]).collect();
+        // Walk the corridors again. If there are two corridors between the junctions,
+        // the hike takes the longer one.
+        let segments: Vec<Vec<Position>> = junctions
+            .windows(2)
+            .map(|pair| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(|direction| self.follow_corridor(pair[0], direction, ignore_slopes))
+                    .filter(|tiles| tiles.last() == Some(&pair[1]))
+                    .max_by_key(Vec::len)
+                    .unwrap()
+            })
+            .collect();
+        Some(Hike {
+            length,
+            junctions,
+            segments,
+        })
+    }
+
+    fn calculate_longest_hike(&self, ignore_slopes: bool) -> Answer {
+        match self.find_longest_hike(ignore_slopes) {
+            Some(hike) => hike.length.into(),
+            None => Answer::Unsolved(String::from("there is no hike from the start to the end")),
+        }
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = HikingTrail;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        HikingTrail::from_reader(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(hiking_trail: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        hiking_trail.calculate_longest_hike(false)
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(hiking_trail: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        hiking_trail.calculate_longest_hike(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, Cursor},
+    };
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day23::solve_first_task(&Day23::parse(reader).unwrap(), &()),
+            Answer::Unsigned(94)
+        );
+    }
+
+    #[test]
+    fn test_gaps() {
+        // The entrance is on the left side, the exit on the right side.
+        let map = "#####\n..#.#\n#...#\n#.#..\n#####";
+        let hiking_trail = Day23::parse(Cursor::new(map)).unwrap();
+        assert_eq!(hiking_trail.pos_start, Position::new(0, 1));
+        assert_eq!(hiking_trail.pos_end, Position::new(4, 3));
+        assert_eq!(
+            hiking_trail.calculate_longest_hike(false),
+            Answer::Unsigned(6)
+        );
+        assert_eq!(
+            Day23::parse(Cursor::new("#.#\n#.#\n...")).unwrap_err(),
+            ParseError::new(3, 2, "'#' (only two gaps in the border)").found("'.'")
+        );
+        assert_eq!(
+            Day23::parse(Cursor::new("#.#\n#.#\n###")).unwrap_err(),
+            ParseError::new(1, 1, "two gaps in the border").found("1")
+        );
+    }
+
+    #[test]
+    fn test_slopes() {
+        // The slope can be entered from the side, but then the next step has to go down
+        // into the forest.
+        let map = "#.###\n#.v.#\n###.#";
+        let hiking_trail = Day23::parse(Cursor::new(map)).unwrap();
+        assert_eq!(hiking_trail.find_longest_hike(false), None);
+        assert_eq!(
+            hiking_trail.calculate_longest_hike(false),
+            Answer::Unsolved(String::from("there is no hike from the start to the end"))
+        );
+        assert_eq!(
+            hiking_trail.calculate_longest_hike(true),
+            Answer::Unsigned(4)
+        );
+        // Walking down the slope is fine.
+        let map = "#.###\n#.>.#\n###.#";
+        let hiking_trail = Day23::parse(Cursor::new(map)).unwrap();
+        assert_eq!(
+            hiking_trail.calculate_longest_hike(false),
+            Answer::Unsigned(4)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day23::solve_second_task(&Day23::parse(reader).unwrap(), &()),
+            Answer::Unsigned(154)
+        );
+    }
+}
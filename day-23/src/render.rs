@@ -0,0 +1,229 @@
+use crate::{Hike, HikingTrail};
+use grid::{Grid, Position};
+use std::io::{self, Write};
+
+/// The colors of the hikes in the pictures (the first hike gets the first color).
+const COLORS: [(&str, [u8; 3]); 4] = [
+    ("red", [220, 40, 40]),
+    ("blue", [40, 90, 220]),
+    ("green", [30, 160, 60]),
+    ("orange", [240, 150, 20]),
+];
+
+/// The size of a tile in the SVG picture.
+const TILE_SIZE: usize = 10;
+
+impl HikingTrail {
+    /// Function that returns the junctions of the map (every tile with more than two
+    /// neighbors, the start and the end).
+    fn get_junctions(&self) -> Vec<Position> {
+        self.map
+            .positions()
+            .filter(|&pos| self.map[pos] != '#' && self.is_junction(pos))
+            .collect()
+    }
+
+    /// Function that shows the hike on the map: The tiles of the hike are 'O', the
+    /// junctions it passes '*' and the other junctions '+', the start is 'S' and the
+    /// end 'E'. Below the map there is one line per segment with its length.
+    pub fn render_hike(&self, hike: &Hike) -> String {
+        let mut map: Grid<char> = self.map.clone();
+        for &pos in self.get_junctions().iter() {
+            map[pos] = '+';
+        }
+        for &pos in hike.segments.iter().flatten() {
+            map[pos] = 'O';
+        }
+        for &pos in hike.junctions.iter() {
+            map[pos] = '*';
+        }
+        map[self.pos_start] = 'S';
+        map[self.pos_end] = 'E';
+        let mut text = map.render(|&c| c);
+        text.push_str(&format!("\nLength: {}", hike.length));
+        for (idx, segment) in hike.segments.iter().enumerate() {
+            let (from, to) = (hike.junctions[idx], hike.junctions[idx + 1]);
+            text.push_str(&format!(
+                "\n{},{} -> {},{}: {}",
+                from.x,
+                from.y,
+                to.x,
+                to.y,
+                segment.len()
+            ));
+        }
+        text
+    }
+
+    /// Function that writes the map with the hikes (each in its own color) as SVG
+    /// picture. The junctions of a hike are dots and the length of every segment is
+    /// written next to its middle tile.
+    pub fn write_svg<W: Write>(&self, hikes: &[&Hike], mut writer: W) -> io::Result<()> {
+        let center = |pos: &Position| {
+            (
+                pos.x * TILE_SIZE + TILE_SIZE / 2,
+                pos.y * TILE_SIZE + TILE_SIZE / 2,
+            )
+        };
+        writeln!(
+            writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">",
+            self.map.width() * TILE_SIZE,
+            self.map.height() * TILE_SIZE
+        )?;
+        writeln!(
+            writer,
+            "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>"
+        )?;
+        // The walls and slopes of a row are drawn as one rectangle per run of tiles.
+        for (y, row) in self.map.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                let is_wall = row[x] == '#';
+                let length = row[x..]
+                    .iter()
+                    .take_while(|&&c| c != '.' && (c == '#') == is_wall)
+                    .count();
+                if length == 0 {
+                    x += 1;
+                    continue;
+                }
+                writeln!(
+                    writer,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x * TILE_SIZE,
+                    y * TILE_SIZE,
+                    length * TILE_SIZE,
+                    TILE_SIZE,
+                    if is_wall { "#555555" } else { "#cccccc" }
+                )?;
+                x += length;
+            }
+        }
+        for (hike, (color, _)) in hikes.iter().zip(COLORS.iter().cycle()) {
+            let points: Vec<String> = hike
+                .junctions
+                .first()
+                .into_iter()
+                .chain(hike.segments.iter().flatten())
+                .map(|pos| {
+                    let (x, y) = center(pos);
+                    format!("{},{}", x, y)
+                })
+                .collect();
+            writeln!(
+                writer,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-opacity=\"0.7\"/>",
+                points.join(" "),
+                color,
+                TILE_SIZE / 2
+            )?;
+            for pos in hike.junctions.iter() {
+                let (x, y) = center(pos);
+                writeln!(
+                    writer,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>",
+                    x,
+                    y,
+                    TILE_SIZE / 2,
+                    color
+                )?;
+            }
+            for segment in hike.segments.iter() {
+                let (x, y) = center(&segment[segment.len() / 2]);
+                writeln!(
+                    writer,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" fill=\"{}\">{}</text>",
+                    x + TILE_SIZE / 2,
+                    y,
+                    TILE_SIZE,
+                    color,
+                    segment.len()
+                )?;
+            }
+        }
+        writeln!(writer, "</svg>")
+    }
+
+    /// Function that draws the map with the hikes (each in its own color) as RGB
+    /// pixels, `scale` pixels per tile. Returns the width, the height and the pixels
+    /// row by row. The junctions of the hikes are black, later hikes are drawn over
+    /// the earlier ones.
+    pub fn render_pixels(&self, hikes: &[&Hike], scale: usize) -> (usize, usize, Vec<u8>) {
+        let mut colors: Grid<[u8; 3]> = self.map.map(|&c| match c {
+            '#' => [85, 85, 85],
+            '.' => [255, 255, 255],
+            _ => [204, 204, 204],
+        });
+        for (hike, (_, color)) in hikes.iter().zip(COLORS.iter().cycle()) {
+            for &pos in hike.segments.iter().flatten() {
+                colors[pos] = *color;
+            }
+            for &pos in hike.junctions.iter() {
+                colors[pos] = [0, 0, 0];
+            }
+        }
+        let (width, height) = (self.map.width() * scale, self.map.height() * scale);
+        let mut pixels: Vec<u8> = Vec::with_capacity(width * height * 3);
+        for row in colors.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|color| std::iter::repeat_n(color, scale).flatten())
+                .copied()
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        (width, height, pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day23;
+    use common::Solution;
+    use grid::Position;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_render_hike() {
+        // Two corridors from the junction at 1,1 to the one at 3,3, the upper one is
+        // longer.
+        let map = "#.#####\n#.....#\n#.###.#\n#.....#\n###.###";
+        let hiking_trail = Day23::parse(Cursor::new(map)).unwrap();
+        let hike = hiking_trail.find_longest_hike(false).unwrap();
+        assert_eq!(hike.length, 10);
+        assert_eq!(
+            hike.junctions,
+            vec![
+                Position::new(1, 0),
+                Position::new(1, 1),
+                Position::new(3, 3),
+                Position::new(3, 4)
+            ]
+        );
+        assert_eq!(hike.segments[1].len(), 8);
+        assert_eq!(
+            hiking_trail.render_hike(&hike),
+            [
+                "#S#####",
+                "#*OOOO#",
+                "#.###O#",
+                "#..*OO#",
+                "###E###",
+                "Length: 10",
+                "1,0 -> 1,1: 1",
+                "1,1 -> 3,3: 8",
+                "3,3 -> 3,4: 1",
+            ]
+            .join("\n")
+        );
+        let (width, height, pixels) = hiking_trail.render_pixels(&[&hike], 2);
+        assert_eq!((width, height, pixels.len()), (14, 10, 14 * 10 * 3));
+        let mut svg: Vec<u8> = Vec::new();
+        hiking_trail.write_svg(&[&hike], &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("<text x=\"60\" y=\"25\" font-size=\"10\" fill=\"red\">8</text>"));
+    }
+}
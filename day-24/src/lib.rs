@@ -1,14 +1,5 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Default)]
-enum Task {
-    #[default]
-    First,
-    Second,
-}
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use std::io::BufRead;
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 struct Vec3 {
@@ -26,14 +17,14 @@ impl Vec3 {
     // We can test this by calculating the cross product. If it is a zero-vector,
     // they are not independent.
     fn independent(&self, other: &Self) -> bool {
-        let cross_product = self.cross(&other);
+        let cross_product = self.cross(other);
         [
             cross_product.x >= f64::EPSILON,
             cross_product.y >= f64::EPSILON,
             cross_product.z >= f64::EPSILON,
         ]
         .iter()
-        .any(|&v| v == true)
+        .any(|&v| v)
     }
 
     // Cross product of two vectors.
@@ -65,11 +56,6 @@ impl Vec3 {
         Self::new(self.x * f, self.y * f, self.z * f)
     }
 
-    // Divide one vector by a scalar.
-    fn div(&self, d: f64) -> Self {
-        Self::new(self.x / d, self.y / d, self.z / d)
-    }
-
     // Create a linear combination from multiple vectors with multiple weights.
     // The result is another vector.
     fn linear_combination(vectors: Vec<Vec3>, factors: Vec<f64>) -> Vec3 {
@@ -83,7 +69,7 @@ impl Vec3 {
 // A hailstone has a position and velocity. We also calculate the 2D line in XY
 // because we need it to check if the paths intersect.
 #[derive(Debug)]
-struct HailStone {
+pub struct HailStone {
     p: Vec3,
     v: Vec3,
     m: f64, // y = m * x + n
@@ -91,25 +77,28 @@ struct HailStone {
 }
 
 impl HailStone {
-    fn from_line(line: String) -> Self {
+    fn from_line(line: String) -> Result<Self, ParseError> {
         // Example line: 19, 13, 30 @ -2,  1, -2
-        let (position, velocity) = line.split_once('@').unwrap();
-        let mut position = position.split(',');
-        let p = Vec3::new(
-            position.next().unwrap().trim().parse::<f64>().unwrap(),
-            position.next().unwrap().trim().parse::<f64>().unwrap(),
-            position.next().unwrap().trim().parse::<f64>().unwrap(),
-        );
-        let mut velocity = velocity.split(',');
-        let v = Vec3::new(
-            velocity.next().unwrap().trim().parse::<f64>().unwrap(),
-            velocity.next().unwrap().trim().parse::<f64>().unwrap(),
-            velocity.next().unwrap().trim().parse::<f64>().unwrap(),
-        );
+        let mut parser = LineParser::new(&line);
+        let p = HailStone::parse_vector(&mut parser)?;
+        parser.skip_whitespace();
+        parser.expect("@")?;
+        let v = HailStone::parse_vector(&mut parser)?;
+        parser.end()?;
         // y = m * x + n
         let m: f64 = v.y / v.x;
         let n: f64 = p.y - (m * p.x);
-        Self { p, v, m, n }
+        Ok(Self { p, v, m, n })
+    }
+
+    /// Function that parses three comma separated numbers.
+    fn parse_vector(parser: &mut LineParser) -> Result<Vec3, ParseError> {
+        let x = parser.number::<f64>()?;
+        parser.expect(",")?;
+        let y = parser.number::<f64>()?;
+        parser.expect(",")?;
+        let z = parser.number::<f64>()?;
+        Ok(Vec3::new(x, y, z))
     }
 
     fn will_cross_2d(&self, other: &Self, pos_min: f64, pos_max: f64) -> bool {
@@ -119,20 +108,20 @@ impl HailStone {
         }
         let x = (other.n - self.n) / (self.m - other.m);
         let y = self.m * x + self.n;
-        if (x < self.p.x as f64 && self.v.x as f64 > 0_f64)
-            || (x > self.p.x as f64 && (self.v.x as f64) < 0_f64)
-            || (x < other.p.x as f64 && other.v.x as f64 > 0_f64)
-            || (x > other.p.x as f64 && (other.v.x as f64) < 0_f64)
+        if (x < self.p.x && self.v.x > 0_f64)
+            || (x > self.p.x && self.v.x < 0_f64)
+            || (x < other.p.x && other.v.x > 0_f64)
+            || (x > other.p.x && other.v.x < 0_f64)
         {
             // Back in time.
             return false;
         }
         if x >= pos_min && x <= pos_max && y >= pos_min && y <= pos_max {
             // Inside test area.
-            return true;
+            true
         } else {
             // Outside test area.
-            return false;
+            false
         }
     }
 
@@ -147,12 +136,7 @@ impl HailStone {
     }
 }
 
-fn solve_first_task<B: BufRead>(reader: B, pos_min: f64, pos_max: f64) -> usize {
-    let hailstones: Vec<HailStone> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(HailStone::from_line)
-        .collect();
+fn count_intersections_in_test_area(hailstones: &[HailStone], pos_min: f64, pos_max: f64) -> usize {
     let mut result = 0;
     for (i, hailstone) in hailstones.iter().enumerate() {
         for other in hailstones.iter().skip(i + 1) {
@@ -164,14 +148,11 @@ fn solve_first_task<B: BufRead>(reader: B, pos_min: f64, pos_max: f64) -> usize
     result
 }
 
-fn solve_second_task<B: BufRead>(reader: B) -> usize {
-    let hailstones: Vec<HailStone> = reader
-        .lines()
-        .map(Result::unwrap)
-        .map(HailStone::from_line)
-        .collect();
+/// Function that calculates the sum of the coordinates of the initial position of the rock
+/// that hits every hailstone.
+fn calculate_rock_position(hailstones: &[HailStone]) -> usize {
     // Find three independent hailstones.
-    let hailstone_1 = hailstones.get(0).unwrap();
+    let hailstone_1 = hailstones.first().unwrap();
     let mut hailstone_2 = hailstones.get(1).unwrap();
     let mut hailstone_3 = hailstones.get(2).unwrap();
     for idx2 in 1..hailstones.len() {
@@ -244,32 +225,52 @@ fn solve_second_task<B: BufRead>(reader: B) -> usize {
     ((rock.x + rock.y + rock.z) / s) as usize
 }
 
-fn main() {
-    let mut args = std::env::args().skip(1);
-    let filename = args.next().unwrap_or_else(|| String::from("./input"));
-    let task = args
-        .next()
-        .map(|arg| match arg.as_str() {
-            "first" => Task::First,
-            "second" => Task::Second,
-            _ => unreachable!(),
-        })
-        .unwrap_or_default();
-    let reader = BufReader::new(File::open(filename).expect("Input file not found."));
+pub struct Parameters {
+    /// The minimum x and y value of the test area of the first task.
+    pub test_area_min: f64,
+    /// The maximum x and y value of the test area of the first task.
+    pub test_area_max: f64,
+}
 
-    println!(
-        "{:?} task solution: {:?}",
-        task,
-        match task {
-            Task::First => solve_first_task(reader, 200000000000000_f64, 400000000000000_f64),
-            Task::Second => solve_second_task(reader),
+impl Default for Parameters {
+    fn default() -> Self {
+        Self {
+            test_area_min: 200000000000000_f64,
+            test_area_max: 400000000000000_f64,
         }
-    )
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<HailStone>;
+    type Parameters = Parameters;
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        parse_lines(reader, HailStone::from_line)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(hailstones: &Self::Input, parameters: &Self::Parameters) -> Answer {
+        count_intersections_in_test_area(
+            hailstones,
+            parameters.test_area_min,
+            parameters.test_area_max,
+        )
+        .into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(hailstones: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        calculate_rock_position(hailstones).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{fs::File, io::BufReader};
 
     #[test]
     fn test_linear_combination() {
@@ -282,15 +283,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            HailStone::from_line(String::from("19, 13, 30 @ -2, 1")).unwrap_err(),
+            ParseError::new(1, 19, "','").found("end of line")
+        );
+    }
+
     #[test]
     fn test_first_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_first_task(reader, 7_f64, 27_f64), 2);
+        assert_eq!(
+            Day24::solve_first_task(
+                &Day24::parse(reader).unwrap(),
+                &Parameters {
+                    test_area_min: 7_f64,
+                    test_area_max: 27_f64
+                }
+            ),
+            Answer::Unsigned(2)
+        );
     }
 
     #[test]
     fn test_second_task() {
         let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
-        assert_eq!(solve_second_task(reader), 47);
+        assert_eq!(
+            Day24::solve_second_task(&Day24::parse(reader).unwrap(), &Parameters::default()),
+            Answer::Unsigned(47)
+        );
     }
 }
@@ -0,0 +1,47 @@
+//! Prints the wires to cut and the components of both groups of a day 25 input. With
+//! a seed, the wires are guessed from random samples instead, e.g.:
+//!
+//! cargo run --release -p day-25 --example wires -- day-25/input 7
+use common::Solution;
+use day_25::Day25;
+use std::{env, fs::File, io::BufReader, process::exit};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (input, seed) = match &args[..] {
+        [input] => (input, None),
+        [input, seed] => match seed.parse::<u64>() {
+            Ok(seed) => (input, Some(seed)),
+            Err(_) => {
+                eprintln!("error: invalid seed '{}' (expected a number)", seed);
+                exit(2);
+            }
+        },
+        _ => {
+            eprintln!("Usage: wires <input> [seed]");
+            exit(2);
+        }
+    };
+    let reader = BufReader::new(File::open(input).expect("Input file not found."));
+    let wiring = Day25::parse(reader).unwrap_or_else(|error| {
+        eprintln!("error: {}", error.with_file(input.clone()));
+        exit(1);
+    });
+    let Some(cut) = wiring.find_cut(seed) else {
+        eprintln!("error: there need to be at least two components to split them into two groups");
+        exit(1);
+    };
+    println!(
+        "Cut {} wires: {}",
+        cut.edges.len(),
+        wiring.get_wire_names(&cut.edges).join(", ")
+    );
+    for (idx, group) in wiring.get_group_names(&cut).iter().enumerate() {
+        println!(
+            "Group {} ({} components): {}",
+            idx + 1,
+            group.len(),
+            group.join(" ")
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use common::{parse_lines, Answer, LineParser, ParseError, Solution};
+use graph::{Cut, Graph, NodeId};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{cmp::Reverse, collections::HashMap, io::BufRead};
+
+/// The number of random pairs of components whose shortest path is sampled before a
+/// wire is cut.
+const NUMBER_OF_SAMPLES: usize = 400;
+
+#[derive(Debug, Default)]
+pub struct Parameters {
+    /// Without a seed, the wires to cut are found by an exact minimum cut. With a seed,
+    /// they are guessed from random samples instead (see `guess_wires_to_cut`).
+    pub seed: Option<u64>,
+}
+
+/// The components and the wires between them.
+#[derive(Debug, Clone)]
+pub struct Wiring {
+    graph: Graph,
+}
+
+impl Wiring {
+    fn from_reader<B: BufRead>(reader: B) -> Result<Self, ParseError> {
+        let mut graph = Graph::new_undirected();
+        for (component, connected_components) in
+            parse_lines(reader, Wiring::get_key_value_pair_from_line)?
+        {
+            for connected_component in connected_components.iter() {
+                graph.add_edge_by_name(&component, connected_component);
+            }
+        }
+        Ok(Self { graph })
+    }
+
+    fn get_key_value_pair_from_line(line: String) -> Result<(String, Vec<String>), ParseError> {
+        // Example line: cmg: qnr nvd lhk bvb
+        // The names only consist of lowercase letters.
+        let is_name = |c: char| c.is_ascii_lowercase();
+        let mut parser = LineParser::new(&line);
+        let key = parser.take_while("a name", is_name)?.to_string();
+        parser.expect(":")?;
+        let mut values: Vec<String> = Vec::new();
+        parser.skip_whitespace();
+        while !parser.is_at_end() {
+            let column = parser.column();
+            let value = parser.take_while("a name", is_name)?;
+            // A component can not be connected to itself.
+            if value == key {
+                return Err(
+                    ParseError::new(1, column, format!("a name other than '{}'", key))
+                        .found(format!("'{}'", value)),
+                );
+            }
+            values.push(value.to_string());
+            parser.skip_whitespace();
+        }
+        if values.is_empty() {
+            return Err(parser.error("a name"));
+        }
+        Ok((key, values))
+    }
+
+    fn remove_connections(&mut self, connections_to_cut: &[(NodeId, NodeId)]) {
+        for &(node_from, node_to) in connections_to_cut.iter() {
+            assert!(
+                self.graph.remove_edge(node_from, node_to),
+                "There is no wire between {} and {}.",
+                self.graph.name(node_from),
+                self.graph.name(node_to)
+            );
+        }
+    }
+
+    /// Function that returns the cut of the given wires: The first group is the group
+    /// of the first component, the second one all other components.
+    fn cut_wires(&self, wires: Vec<(NodeId, NodeId)>) -> Cut {
+        let mut wiring = self.clone();
+        wiring.remove_connections(&wires);
+        let mut first_group = wiring.graph.bfs_order(0);
+        first_group.sort();
+        let is_in_first_group = |node: &NodeId| first_group.binary_search(node).is_ok();
+        let second_group: Vec<NodeId> = self
+            .graph
+            .nodes()
+            .filter(|node| !is_in_first_group(node))
+            .collect();
+        let edges: Vec<(NodeId, NodeId)> = wires
+            .into_iter()
+            .map(|(node_from, node_to)| match is_in_first_group(&node_from) {
+                true => (node_from, node_to),
+                false => (node_to, node_from),
+            })
+            .collect();
+        Cut {
+            weight: edges.len() as u64,
+            edges,
+            groups: [first_group, second_group],
+        }
+    }
+
+    /// Function that returns the names of the components at both ends of every wire
+    /// (e.g. "hfx/pzl").
+    pub fn get_wire_names(&self, wires: &[(NodeId, NodeId)]) -> Vec<String> {
+        wires
+            .iter()
+            .map(|&(node_from, node_to)| {
+                format!(
+                    "{}/{}",
+                    self.graph.name(node_from),
+                    self.graph.name(node_to)
+                )
+            })
+            .collect()
+    }
+
+    /// Function that returns the names of the components of both groups of the cut
+    /// (each sorted by name).
+    pub fn get_group_names(&self, cut: &Cut) -> [Vec<&str>; 2] {
+        cut.groups.each_ref().map(|group| {
+            let mut names: Vec<&str> = group.iter().map(|&node| self.graph.name(node)).collect();
+            names.sort();
+            names
+        })
+    }
+
+    /// Function that finds the wires to cut so that the components split into two
+    /// groups: Without a seed by an exact minimum cut, with a seed they are guessed
+    /// from random samples. Returns None if there are less than two components.
+    pub fn find_cut(&self, seed: Option<u64>) -> Option<Cut> {
+        match seed {
+            Some(seed) => (self.graph.node_count() >= 2)
+                .then(|| self.cut_wires(self.guess_wires_to_cut(&mut StdRng::seed_from_u64(seed)))),
+            None => self.find_wires_to_cut(),
+        }
+    }
+
+    /// Function that finds the fewest wires to cut so that the components split into
+    /// two groups (None if there are less than two components).
+    fn find_wires_to_cut(&self) -> Option<Cut> {
+        self.graph.minimum_cut()
+    }
+
+    /// Function that guesses the wires to cut: The wire that is used most by the
+    /// shortest paths between random pairs of components is cut, until the components
+    /// split into two groups. The random numbers come from the given generator, so a
+    /// run can be repeated with the same seed.
+    fn guess_wires_to_cut<R: Rng>(&self, rng: &mut R) -> Vec<(NodeId, NodeId)> {
+        assert!(
+            self.graph.node_count() >= 2,
+            "There need to be at least two components."
+        );
+        let mut graph = self.graph.clone();
+        let mut wires_to_cut: Vec<(NodeId, NodeId)> = Vec::new();
+        while graph.bfs_order(0).len() == graph.node_count() {
+            let mut lookup: HashMap<(NodeId, NodeId), usize> = HashMap::new();
+            for _ in 0..NUMBER_OF_SAMPLES {
+                let node_from = rng.gen_range(0..graph.node_count());
+                let node_to = rng.gen_range(0..graph.node_count());
+                if node_from == node_to {
+                    continue;
+                }
+                let path = graph
+                    .shortest_path(node_from, node_to)
+                    .expect("No path found.");
+                for walk in path.windows(2) {
+                    let walk_count = lookup
+                        .entry((walk[0].min(walk[1]), walk[0].max(walk[1])))
+                        .or_insert(0);
+                    *walk_count += 1;
+                }
+            }
+            // The order of a hash map differs between runs, so ties are broken by the
+            // smaller wire.
+            let (&wire, _) = lookup
+                .iter()
+                .max_by_key(|&(&wire, &walk_count)| (walk_count, Reverse(wire)))
+                .expect("No path was sampled.");
+            graph.remove_edge(wire.0, wire.1);
+            wires_to_cut.push(wire);
+        }
+        wires_to_cut
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Wiring;
+    type Parameters = Parameters;
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        Wiring::from_reader(reader)
+    }
+
+    fn parameters_with_seed(seed: u64) -> Option<Self::Parameters> {
+        Some(Parameters { seed: Some(seed) })
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(wiring: &Self::Input, parameters: &Self::Parameters) -> Answer {
+        match wiring.find_cut(parameters.seed) {
+            // The components might not be connected at all, then there is nothing to cut.
+            Some(cut) => (cut.groups[0].len() * cut.groups[1].len()).into(),
+            None => Answer::Unsolved(String::from(
+                "there need to be at least two components to split them into two groups",
+            )),
+        }
+    }
+
+    /// There is no second task on the last day.
+    fn solve_second_task(_wiring: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        0_usize.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        fs::File,
+        io::{BufReader, Cursor},
+    };
+
+    #[test]
+    fn test_first_task_subfunctions() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        let wiring = Wiring::from_reader(reader).unwrap();
+        let id = |name: &str| wiring.graph.node_id(name).unwrap();
+        let cut = wiring.cut_wires(vec![
+            (id("hfx"), id("pzl")),
+            (id("bvb"), id("cmg")),
+            (id("nvd"), id("jqt")),
+        ]);
+        assert_eq!(cut.groups[0].len() * cut.groups[1].len(), 54);
+        // The first group contains the first component (jqt).
+        assert_eq!(
+            wiring.get_wire_names(&cut.edges),
+            vec!["hfx/pzl", "bvb/cmg", "jqt/nvd"]
+        );
+        assert_eq!(
+            wiring.get_group_names(&cut),
+            [
+                vec!["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"],
+                vec!["cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_wires_to_cut() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        let wiring = Wiring::from_reader(reader).unwrap();
+        let cut = wiring.find_wires_to_cut().unwrap();
+        let mut wires: Vec<[&str; 2]> = cut
+            .edges
+            .iter()
+            .map(|&(from, to)| {
+                let mut wire = [wiring.graph.name(from), wiring.graph.name(to)];
+                wire.sort();
+                wire
+            })
+            .collect();
+        wires.sort();
+        assert_eq!(cut.weight, 3);
+        assert_eq!(wires, vec![["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+        assert_eq!((cut.groups[0].len(), cut.groups[1].len()), (6, 9));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Wiring::get_key_value_pair_from_line(String::from("cmg: qnr Nvd")).unwrap_err(),
+            ParseError::new(1, 10, "a name").found("'N'")
+        );
+        assert_eq!(
+            Wiring::get_key_value_pair_from_line(String::from("cmg: qnr cmg")).unwrap_err(),
+            ParseError::new(1, 10, "a name other than 'cmg'").found("'cmg'")
+        );
+    }
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day25::solve_first_task(&Day25::parse(reader).unwrap(), &Parameters::default()),
+            Answer::Unsigned(54)
+        );
+    }
+
+    #[test]
+    fn test_first_task_with_seed() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        let wiring = Day25::parse(reader).unwrap();
+        for seed in [0, 1, 2, 25, 2023] {
+            assert_eq!(
+                Day25::solve_first_task(&wiring, &Day25::parameters_with_seed(seed).unwrap()),
+                Answer::Unsigned(54)
+            );
+        }
+        // The same seed cuts the same wires.
+        assert_eq!(
+            wiring.guess_wires_to_cut(&mut StdRng::seed_from_u64(7)),
+            wiring.guess_wires_to_cut(&mut StdRng::seed_from_u64(7))
+        );
+    }
+
+    #[test]
+    fn test_first_task_disconnected() {
+        // There is no wire between the two groups, so none has to be cut.
+        let wiring = Day25::parse(Cursor::new("aaa: bbb\nccc: ddd eee")).unwrap();
+        for parameters in [Parameters::default(), Parameters { seed: Some(7) }] {
+            assert_eq!(
+                Day25::solve_first_task(&wiring, &parameters),
+                Answer::Unsigned(6)
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_task_without_components() {
+        let wiring = Day25::parse(Cursor::new("")).unwrap();
+        for parameters in [Parameters::default(), Parameters { seed: Some(7) }] {
+            assert_eq!(
+                Day25::solve_first_task(&wiring, &parameters),
+                Answer::Unsolved(String::from(
+                    "there need to be at least two components to split them into two groups"
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            Day25::solve_second_task(&Day25::parse(reader).unwrap(), &Parameters::default()),
+            Answer::Unsigned(0)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Graph, NodeId};
+
+/// Function that quotes the name so that it can be used as an ID in the DOT language.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A group of nodes that is drawn in a box of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cluster {
+    pub label: String,
+    pub nodes: Vec<NodeId>,
+}
+
+impl Graph {
+    /// Function that creates a description of the graph in the DOT language of
+    /// Graphviz. Weights other than 1 are shown as labels of the edges.
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with(|_| String::new(), &[])
+    }
+
+    /// Function that creates a description of the graph in the DOT language like
+    /// `to_dot`, with the attributes for every node (e.g. "shape=box", empty for
+    /// none) and the nodes of the clusters drawn in boxes.
+    pub fn to_dot_with<F: Fn(NodeId) -> String>(
+        &self,
+        node_attributes: F,
+        clusters: &[Cluster],
+    ) -> String {
+        let (keyword, arrow) = if self.is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let node_line = |node: NodeId, indent: &str| {
+            let attributes = node_attributes(node);
+            if attributes.is_empty() {
+                format!("{}{};\n", indent, quote(self.name(node)))
+            } else {
+                format!("{}{} [{}];\n", indent, quote(self.name(node)), attributes)
+            }
+        };
+        let mut dot = format!("{} {{\n", keyword);
+        let mut in_cluster = vec![false; self.node_count()];
+        for (idx, cluster) in clusters.iter().enumerate() {
+            dot.push_str(&format!("    subgraph cluster_{} {{\n", idx));
+            dot.push_str(&format!("        label={};\n", quote(&cluster.label)));
+            for &node in cluster.nodes.iter() {
+                in_cluster[node] = true;
+                dot.push_str(&node_line(node, "        "));
+            }
+            dot.push_str("    }\n");
+        }
+        for node in self.nodes().filter(|&node| !in_cluster[node]) {
+            dot.push_str(&node_line(node, "    "));
+        }
+        for from in self.nodes() {
+            for edge in self.edges(from) {
+                // An undirected edge is stored at both nodes but is written only once.
+                if !self.is_directed() && edge.to < from {
+                    continue;
+                }
+                dot.push_str(&format!(
+                    "    {} {} {}",
+                    quote(self.name(from)),
+                    arrow,
+                    quote(self.name(edge.to))
+                ));
+                if edge.weight != 1 {
+                    dot.push_str(&format!(" [label=\"{}\"]", edge.weight));
+                }
+                dot.push_str(";\n");
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cluster;
+    use crate::Graph;
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::new_undirected();
+        graph.add_edge_by_name("a", "b");
+        let c = graph.add_node("c\"");
+        graph.add_weighted_edge(c, 0, 7);
+        assert_eq!(
+            graph.to_dot(),
+            [
+                "graph {",
+                "    \"a\";",
+                "    \"b\";",
+                "    \"c\\\"\";",
+                "    \"a\" -- \"b\";",
+                "    \"a\" -- \"c\\\"\" [label=\"7\"];",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_to_dot_with() {
+        let mut graph = Graph::new_directed();
+        graph.add_edge_by_name("a", "b");
+        graph.add_edge_by_name("b", "c");
+        let clusters = [Cluster {
+            label: String::from("first"),
+            nodes: vec![0, 1],
+        }];
+        let attributes = |node| match node {
+            2 => String::from("shape=box"),
+            _ => String::new(),
+        };
+        assert_eq!(
+            graph.to_dot_with(attributes, &clusters),
+            [
+                "digraph {",
+                "    subgraph cluster_0 {",
+                "        label=\"first\";",
+                "        \"a\";",
+                "        \"b\";",
+                "    }",
+                "    \"c\" [shape=box];",
+                "    \"a\" -> \"b\";",
+                "    \"b\" -> \"c\";",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::Interner;
+
+/// The number of a node. The nodes are numbered in the order they were added.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    pub to: NodeId,
+    pub weight: u64,
+}
+
+/// A graph whose nodes have names. The edges of a node keep the order in which they
+/// were added and there may be more than one edge between two nodes. In an undirected
+/// graph every edge is stored at both of its nodes.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    directed: bool,
+    names: Interner,
+    adjacency: Vec<Vec<Edge>>,
+    number_of_edges: usize,
+}
+
+impl Graph {
+    pub fn new_directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn new_undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            directed,
+            names: Interner::new(),
+            adjacency: Vec::new(),
+            number_of_edges: 0,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Function that returns the node with the name and adds it if it is new.
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        let id = self.names.intern(name);
+        if id == self.adjacency.len() {
+            self.adjacency.push(Vec::new());
+        }
+        id
+    }
+
+    pub fn node_id(&self, name: &str) -> Option<NodeId> {
+        self.names.get(name)
+    }
+
+    pub fn name(&self, node: NodeId) -> &str {
+        self.names.name(node)
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.number_of_edges
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.node_count()
+    }
+
+    /// Function that adds an edge with the weight 1.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    pub fn add_weighted_edge(&mut self, from: NodeId, to: NodeId, weight: u64) {
+        self.adjacency[from].push(Edge { to, weight });
+        if !self.directed && from != to {
+            self.adjacency[to].push(Edge { to: from, weight });
+        }
+        self.number_of_edges += 1;
+    }
+
+    /// Function that adds an edge with the weight 1 between the named nodes and adds
+    /// the nodes if they are new.
+    pub fn add_edge_by_name(&mut self, from: &str, to: &str) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge(from, to);
+    }
+
+    /// Function that removes one edge between the nodes. Returns false if there is none.
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> bool {
+        let position = match self.adjacency[from].iter().position(|edge| edge.to == to) {
+            Some(position) => position,
+            None => return false,
+        };
+        self.adjacency[from].remove(position);
+        if !self.directed && from != to {
+            let position = self.adjacency[to]
+                .iter()
+                .position(|edge| edge.to == from)
+                .unwrap();
+            self.adjacency[to].remove(position);
+        }
+        self.number_of_edges -= 1;
+        true
+    }
+
+    /// Function that returns the outgoing edges of the node (all edges if the graph is
+    /// undirected).
+    pub fn edges(&self, node: NodeId) -> &[Edge] {
+        &self.adjacency[node]
+    }
+
+    pub fn neighbors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.adjacency[node].iter().map(|edge| edge.to)
+    }
+
+    /// Function that returns the nodes with an edge to the node (once per edge).
+    pub fn predecessors(&self, node: NodeId) -> Vec<NodeId> {
+        if !self.directed {
+            return self.neighbors(node).collect();
+        }
+        self.nodes()
+            .flat_map(|from| {
+                self.neighbors(from)
+                    .filter(move |&to| to == node)
+                    .map(move |_| from)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges() {
+        let mut graph = Graph::new_undirected();
+        graph.add_edge_by_name("a", "b");
+        graph.add_edge_by_name("a", "c");
+        let (a, b, c) = (0, 1, 2);
+        assert_eq!(graph.node_id("c"), Some(c));
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.neighbors(b).collect::<Vec<NodeId>>(), vec![a]);
+        assert!(graph.remove_edge(b, a));
+        assert!(!graph.remove_edge(a, b));
+        assert_eq!(graph.neighbors(a).collect::<Vec<NodeId>>(), vec![c]);
+        assert_eq!(graph.edge_count(), 1);
+
+        let mut graph = Graph::new_directed();
+        graph.add_edge_by_name("a", "b");
+        graph.add_edge_by_name("c", "b");
+        assert_eq!(graph.neighbors(b).count(), 0);
+        assert_eq!(graph.predecessors(b), vec![a, c]);
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+/// Gives every distinct name a small number (starting at 0, in the order the names
+/// were seen) and remembers the name of every number.
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Function that returns the number of the name and adds the name if it is new.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut interner = Interner::new();
+        assert_eq!(interner.intern("jqt"), 0);
+        assert_eq!(interner.intern("rhn"), 1);
+        assert_eq!(interner.intern("jqt"), 0);
+        assert_eq!(interner.get("rhn"), Some(1));
+        assert_eq!(interner.get("xhk"), None);
+        assert_eq!(interner.name(1), "rhn");
+        assert_eq!(interner.len(), 2);
+    }
+}
@@ -0,0 +1,11 @@
+mod dot;
+mod graph;
+mod interner;
+mod longest_path;
+mod min_cut;
+mod traversal;
+
+pub use dot::Cluster;
+pub use graph::{Edge, Graph, NodeId};
+pub use interner::Interner;
+pub use min_cut::Cut;
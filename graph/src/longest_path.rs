@@ -0,0 +1,151 @@
+use crate::{Graph, NodeId};
+
+/// The state of the depth first search for the longest simple path.
+struct LongestPathSearch<'a> {
+    graph: &'a Graph,
+    to: NodeId,
+    use_upper_bound: bool,
+    /// The only node with an edge to the end (if there is only one). A path that gets
+    /// there has to go to the end at once, the end can not be reached later.
+    last_node: Option<NodeId>,
+    /// The largest weight of the edges leaving every node (0 for the end).
+    largest_weights: Vec<u64>,
+    /// The nodes of the path (by node ID).
+    visited: Vec<bool>,
+    path: Vec<NodeId>,
+    best: Option<(u64, Vec<NodeId>)>,
+}
+
+impl LongestPathSearch<'_> {
+    /// Function that continues the path at the node (already part of the path).
+    /// `remaining_weight` is the sum of the largest weights of the nodes that are not
+    /// visited yet, so that the path can not get longer than the current length plus
+    /// the largest weight of the node plus the remaining weight.
+    fn search(&mut self, node: NodeId, length: u64, remaining_weight: u64) {
+        if node == self.to {
+            if self.best.as_ref().is_none_or(|(best, _)| length > *best) {
+                self.best = Some((length, self.path.clone()));
+            }
+            return;
+        }
+        if self.use_upper_bound {
+            let upper_bound = length + self.largest_weights[node] + remaining_weight;
+            if self
+                .best
+                .as_ref()
+                .is_some_and(|(best, _)| upper_bound <= *best)
+            {
+                return;
+            }
+        }
+        for edge in self.graph.edges(node) {
+            if self.visited[edge.to] || (self.last_node == Some(node) && edge.to != self.to) {
+                continue;
+            }
+            self.visited[edge.to] = true;
+            self.path.push(edge.to);
+            self.search(
+                edge.to,
+                length + edge.weight,
+                remaining_weight - self.largest_weights[edge.to],
+            );
+            self.path.pop();
+            self.visited[edge.to] = false;
+        }
+    }
+}
+
+impl Graph {
+    /// Function that finds a path with the largest sum of weights that does not visit
+    /// a node twice (depth first search over all such paths) and returns the sum and
+    /// the path (containing both ends). With the upper bound, paths that can not get
+    /// longer than the best path found so far are not followed any further, which
+    /// makes the search much faster on most graphs.
+    pub fn longest_simple_path(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        use_upper_bound: bool,
+    ) -> Option<(u64, Vec<NodeId>)> {
+        let largest_weights: Vec<u64> = self
+            .nodes()
+            .map(|node| {
+                if node == to {
+                    return 0;
+                }
+                self.edges(node)
+                    .iter()
+                    .map(|edge| edge.weight)
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let predecessors = self.predecessors(to);
+        let last_node = (!predecessors.is_empty()
+            && predecessors.iter().all(|&node| node == predecessors[0]))
+        .then(|| predecessors[0]);
+        let remaining_weight = largest_weights.iter().sum::<u64>() - largest_weights[from];
+        let mut visited = vec![false; self.node_count()];
+        visited[from] = true;
+        let mut search = LongestPathSearch {
+            graph: self,
+            to,
+            use_upper_bound,
+            last_node,
+            largest_weights,
+            visited,
+            path: vec![from],
+            best: None,
+        };
+        search.search(from, 0, remaining_weight);
+        search.best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    #[test]
+    fn test_longest_simple_path() {
+        // a -> b -> d is shorter than a -> c -> b -> d, e can not be reached.
+        let mut graph = Graph::new_directed();
+        for name in ["a", "b", "c", "d", "e"] {
+            graph.add_node(name);
+        }
+        graph.add_weighted_edge(0, 1, 5);
+        graph.add_weighted_edge(0, 2, 1);
+        graph.add_weighted_edge(2, 1, 6);
+        graph.add_weighted_edge(1, 3, 1);
+        graph.add_weighted_edge(3, 0, 9);
+        for use_upper_bound in [false, true] {
+            assert_eq!(
+                graph.longest_simple_path(0, 3, use_upper_bound),
+                Some((8, vec![0, 2, 1, 3]))
+            );
+            assert_eq!(graph.longest_simple_path(0, 4, use_upper_bound), None);
+            assert_eq!(
+                graph.longest_simple_path(1, 1, use_upper_bound),
+                Some((0, vec![1]))
+            );
+        }
+    }
+
+    #[test]
+    fn test_longest_simple_path_of_many_nodes() {
+        // A chain of 200 nodes with a shortcut from the first to the last one.
+        let mut graph = Graph::new_directed();
+        for idx in 0..200 {
+            graph.add_node(&idx.to_string());
+        }
+        for idx in 0..199 {
+            graph.add_weighted_edge(idx, idx + 1, 1);
+        }
+        graph.add_weighted_edge(0, 199, 10);
+        for use_upper_bound in [false, true] {
+            let (length, path) = graph.longest_simple_path(0, 199, use_upper_bound).unwrap();
+            assert_eq!(length, 199);
+            assert_eq!(path, (0..200).collect::<Vec<_>>());
+        }
+    }
+}
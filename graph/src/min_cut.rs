@@ -0,0 +1,160 @@
+use crate::{Graph, NodeId};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// A split of the nodes into two groups together with the edges between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    /// The sum of the weights of the cut edges.
+    pub weight: u64,
+    /// The cut edges as (node of the first group, node of the second group).
+    pub edges: Vec<(NodeId, NodeId)>,
+    /// Both groups, each sorted. The first group contains the first node.
+    pub groups: [Vec<NodeId>; 2],
+}
+
+impl Graph {
+    /// Function that finds a cut with the smallest weight of an undirected graph
+    /// (Stoer-Wagner). The result does not depend on chance, so the same graph always
+    /// gives the same cut. Returns None if there are less than two nodes.
+    pub fn minimum_cut(&self) -> Option<Cut> {
+        assert!(
+            !self.is_directed(),
+            "The minimum cut is only defined for undirected graphs."
+        );
+        if self.node_count() < 2 {
+            return None;
+        }
+        // The weights between the (merged) nodes. Self loops never cross a cut.
+        let mut weights: Vec<HashMap<NodeId, u64>> = vec![HashMap::new(); self.node_count()];
+        for from in self.nodes() {
+            for edge in self.edges(from).iter().filter(|edge| edge.to != from) {
+                *weights[from].entry(edge.to).or_insert(0) += edge.weight;
+            }
+        }
+        let mut merged_nodes: Vec<Vec<NodeId>> = self.nodes().map(|node| vec![node]).collect();
+        let mut active_nodes: Vec<NodeId> = self.nodes().collect();
+        let mut best: Option<(u64, Vec<NodeId>)> = None;
+        while active_nodes.len() > 1 {
+            let (weight, previous, last) = Self::minimum_cut_phase(&weights, &active_nodes);
+            if best
+                .as_ref()
+                .is_none_or(|&(best_weight, _)| weight < best_weight)
+            {
+                best = Some((weight, merged_nodes[last].clone()));
+            }
+            // Merge the last node into the previous one.
+            let last_nodes = std::mem::take(&mut merged_nodes[last]);
+            merged_nodes[previous].extend(last_nodes);
+            for (node, weight) in std::mem::take(&mut weights[last]) {
+                weights[node].remove(&last);
+                if node != previous {
+                    *weights[previous].entry(node).or_insert(0) += weight;
+                    *weights[node].entry(previous).or_insert(0) += weight;
+                }
+            }
+            active_nodes.retain(|&node| node != last);
+        }
+        let (weight, side) = best.unwrap();
+        let mut in_side = vec![false; self.node_count()];
+        for &node in side.iter() {
+            in_side[node] = true;
+        }
+        // The group with the first node comes first.
+        let in_first_group = |node: NodeId| in_side[node] == in_side[0];
+        let (first_group, second_group): (Vec<NodeId>, Vec<NodeId>) =
+            self.nodes().partition(|&node| in_first_group(node));
+        let edges: Vec<(NodeId, NodeId)> = first_group
+            .iter()
+            .flat_map(|&from| {
+                self.neighbors(from)
+                    .filter(|&to| !in_first_group(to))
+                    .map(move |to| (from, to))
+            })
+            .collect();
+        Some(Cut {
+            weight,
+            edges,
+            groups: [first_group, second_group],
+        })
+    }
+
+    /// Function that adds the active nodes one by one, always the one that is connected
+    /// most strongly to the nodes added so far. Returns the weight between the last
+    /// node and all others (the cut of the phase), the previous and the last node.
+    fn minimum_cut_phase(
+        weights: &[HashMap<NodeId, u64>],
+        active_nodes: &[NodeId],
+    ) -> (u64, NodeId, NodeId) {
+        let mut connectivity: Vec<u64> = vec![0; weights.len()];
+        let mut added = vec![false; weights.len()];
+        // Ties are broken by the smaller node, so that the result is always the same.
+        let mut queue: BinaryHeap<(u64, Reverse<NodeId>)> = active_nodes
+            .iter()
+            .map(|&node| (0, Reverse(node)))
+            .collect();
+        let (mut previous, mut last) = (active_nodes[0], active_nodes[0]);
+        while let Some((value, Reverse(node))) = queue.pop() {
+            // Skip the outdated entries, the connectivity only grows.
+            if added[node] || value != connectivity[node] {
+                continue;
+            }
+            added[node] = true;
+            (previous, last) = (last, node);
+            for (&neighbor, &weight) in weights[node].iter() {
+                if !added[neighbor] {
+                    connectivity[neighbor] += weight;
+                    queue.push((connectivity[neighbor], Reverse(neighbor)));
+                }
+            }
+        }
+        (connectivity[last], previous, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Graph;
+
+    #[test]
+    fn test_minimum_cut() {
+        // Two squares with diagonals (a, b, c, d and e, f, g, h), connected by two edges.
+        let mut graph = Graph::new_undirected();
+        for (from, to) in [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "d"),
+            ("d", "a"),
+            ("a", "c"),
+            ("b", "d"),
+            ("e", "f"),
+            ("f", "g"),
+            ("g", "h"),
+            ("h", "e"),
+            ("e", "g"),
+            ("f", "h"),
+            ("c", "e"),
+            ("h", "b"),
+        ] {
+            graph.add_edge_by_name(from, to);
+        }
+        let cut = graph.minimum_cut().unwrap();
+        assert_eq!(cut.weight, 2);
+        assert_eq!(cut.edges, vec![(1, 7), (2, 4)]);
+        assert_eq!(cut.groups, [vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+
+        // A heavy edge is not cut, a node without edges is cut off for free.
+        let mut graph = Graph::new_undirected();
+        graph.add_edge_by_name("a", "b");
+        let (a, b, c) = (0, 1, graph.add_node("c"));
+        graph.add_weighted_edge(b, c, 5);
+        assert_eq!(graph.minimum_cut().unwrap().edges, vec![(a, b)]);
+        graph.add_node("d");
+        let cut = graph.minimum_cut().unwrap();
+        assert_eq!((cut.weight, cut.edges.len()), (0, 0));
+        assert_eq!(cut.groups, [vec![0, 1, 2], vec![3]]);
+        assert_eq!(Graph::new_undirected().minimum_cut(), None);
+    }
+}
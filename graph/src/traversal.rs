@@ -0,0 +1,280 @@
+use crate::{Graph, NodeId};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+impl Graph {
+    /// Function that returns all nodes reachable from the start in breadth first order
+    /// (including the start).
+    pub fn bfs_order(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.node_count()];
+        let mut order: Vec<NodeId> = Vec::new();
+        let mut queue: VecDeque<NodeId> = VecDeque::from([start]);
+        visited[start] = true;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for neighbor in self.neighbors(node) {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        order
+    }
+
+    /// Function that returns all nodes reachable from the start in depth first order
+    /// (including the start). The edges of a node are followed in their order.
+    pub fn dfs_order(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.node_count()];
+        let mut order: Vec<NodeId> = Vec::new();
+        let mut stack: Vec<NodeId> = vec![start];
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order.push(node);
+            // Reversed, so that the first edge is on top of the stack.
+            let neighbors: Vec<NodeId> = self.neighbors(node).collect();
+            stack.extend(neighbors.into_iter().rev().filter(|&n| !visited[n]));
+        }
+        order
+    }
+
+    /// Function that finds a path with the least number of edges (ignoring the weights).
+    /// The path contains both ends.
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<Vec<NodeId>> {
+        let mut parents: Vec<Option<NodeId>> = vec![None; self.node_count()];
+        let mut queue: VecDeque<NodeId> = VecDeque::from([from]);
+        parents[from] = Some(from);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                return Some(Self::collect_path(&parents, from, to));
+            }
+            for neighbor in self.neighbors(node) {
+                if parents[neighbor].is_none() {
+                    parents[neighbor] = Some(node);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        None
+    }
+
+    /// Function that calculates the smallest sum of weights from the start to every
+    /// node (None if the node is not reachable) and the node we came from.
+    fn dijkstra_with_parents(&self, start: NodeId) -> (Vec<Option<u64>>, Vec<Option<NodeId>>) {
+        let mut distances: Vec<Option<u64>> = vec![None; self.node_count()];
+        let mut parents: Vec<Option<NodeId>> = vec![None; self.node_count()];
+        // The binary heap returns the largest element first, so the ordering is reversed.
+        let mut queue = BinaryHeap::from([(Reverse(0), start)]);
+        distances[start] = Some(0);
+        parents[start] = Some(start);
+        while let Some((Reverse(distance), node)) = queue.pop() {
+            if distances[node].is_some_and(|best| best < distance) {
+                continue;
+            }
+            for edge in self.edges(node) {
+                let new_distance = distance + edge.weight;
+                if distances[edge.to].is_none_or(|best| new_distance < best) {
+                    distances[edge.to] = Some(new_distance);
+                    parents[edge.to] = Some(node);
+                    queue.push((Reverse(new_distance), edge.to));
+                }
+            }
+        }
+        (distances, parents)
+    }
+
+    /// Function that calculates the smallest sum of weights from the start to every
+    /// node (None if the node is not reachable).
+    pub fn dijkstra(&self, start: NodeId) -> Vec<Option<u64>> {
+        self.dijkstra_with_parents(start).0
+    }
+
+    /// Function that finds a path with the smallest sum of weights and returns the sum
+    /// and the path (containing both ends).
+    pub fn shortest_weighted_path(&self, from: NodeId, to: NodeId) -> Option<(u64, Vec<NodeId>)> {
+        let (distances, parents) = self.dijkstra_with_parents(from);
+        distances[to].map(|distance| (distance, Self::collect_path(&parents, from, to)))
+    }
+
+    fn collect_path(parents: &[Option<NodeId>], from: NodeId, to: NodeId) -> Vec<NodeId> {
+        let mut path = vec![to];
+        let mut node = to;
+        while node != from {
+            node = parents[node].unwrap();
+            path.push(node);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Function that groups the nodes into connected components. The direction of the
+    /// edges is ignored. Every component is sorted and the components are sorted by
+    /// their first node.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Union find with path halving.
+        let mut parents: Vec<NodeId> = self.nodes().collect();
+        fn find(parents: &mut [NodeId], mut node: NodeId) -> NodeId {
+            while parents[node] != node {
+                parents[node] = parents[parents[node]];
+                node = parents[node];
+            }
+            node
+        }
+        for from in self.nodes() {
+            for to in self.neighbors(from) {
+                let (root_from, root_to) = (find(&mut parents, from), find(&mut parents, to));
+                // The smaller root wins, so that the root is the first node.
+                parents[root_from.max(root_to)] = root_from.min(root_to);
+            }
+        }
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut component_of_root: Vec<Option<usize>> = vec![None; self.node_count()];
+        for node in self.nodes() {
+            let root = find(&mut parents, node);
+            match component_of_root[root] {
+                Some(component) => components[component].push(node),
+                None => {
+                    component_of_root[root] = Some(components.len());
+                    components.push(vec![node]);
+                }
+            }
+        }
+        components
+    }
+
+    /// Function that groups the nodes into strongly connected components, i.e. every
+    /// node of a component can be reached from every other node of it (Tarjan's
+    /// algorithm without recursion, so that large graphs do not overflow the stack).
+    /// Every component is sorted and comes after all components that can be reached
+    /// from it.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut indices: Vec<Option<usize>> = vec![None; self.node_count()];
+        let mut low_links: Vec<usize> = vec![0; self.node_count()];
+        let mut on_stack = vec![false; self.node_count()];
+        let mut stack: Vec<NodeId> = Vec::new();
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut next_index = 0;
+        for root in self.nodes() {
+            if indices[root].is_some() {
+                continue;
+            }
+            // The nodes of the current path with the index of their next edge.
+            let mut path: Vec<(NodeId, usize)> = vec![(root, 0)];
+            indices[root] = Some(next_index);
+            low_links[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some(&(node, edge_idx)) = path.last() {
+                if let Some(edge) = self.edges(node).get(edge_idx) {
+                    path.last_mut().unwrap().1 += 1;
+                    match indices[edge.to] {
+                        None => {
+                            indices[edge.to] = Some(next_index);
+                            low_links[edge.to] = next_index;
+                            next_index += 1;
+                            stack.push(edge.to);
+                            on_stack[edge.to] = true;
+                            path.push((edge.to, 0));
+                        }
+                        Some(index) if on_stack[edge.to] => {
+                            low_links[node] = low_links[node].min(index);
+                        }
+                        Some(_) => (),
+                    }
+                    continue;
+                }
+                // All edges of the node are done.
+                path.pop();
+                if let Some(&(parent, _)) = path.last() {
+                    low_links[parent] = low_links[parent].min(low_links[node]);
+                }
+                if Some(low_links[node]) == indices[node] {
+                    let mut component: Vec<NodeId> = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Graph, NodeId};
+
+    // a -> b -> d, a -> c -> d and e -> f.
+    fn example() -> Graph {
+        let mut graph = Graph::new_directed();
+        for name in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(name);
+        }
+        graph.add_weighted_edge(0, 1, 5);
+        graph.add_weighted_edge(0, 2, 1);
+        graph.add_weighted_edge(1, 3, 1);
+        graph.add_weighted_edge(2, 3, 2);
+        graph.add_edge(4, 5);
+        graph
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = example();
+        assert_eq!(graph.bfs_order(0), vec![0, 1, 2, 3]);
+        assert_eq!(graph.dfs_order(0), vec![0, 1, 3, 2]);
+        assert_eq!(graph.bfs_order(3), vec![3]);
+        assert_eq!(graph.shortest_path(0, 3), Some(vec![0, 1, 3]));
+        assert_eq!(graph.shortest_path(3, 0), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let graph = example();
+        assert_eq!(
+            graph.dijkstra(0),
+            vec![Some(0), Some(5), Some(1), Some(3), None, None]
+        );
+        assert_eq!(graph.shortest_weighted_path(0, 3), Some((3, vec![0, 2, 3])));
+        assert_eq!(graph.shortest_weighted_path(0, 4), None);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = example();
+        let components: Vec<Vec<NodeId>> = vec![vec![0, 1, 2, 3], vec![4, 5]];
+        assert_eq!(graph.connected_components(), components);
+        graph.remove_edge(4, 5);
+        assert_eq!(graph.connected_components().len(), 3);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let mut graph = example();
+        // Without cycles every node is a component of its own.
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![3], vec![1], vec![2], vec![0], vec![5], vec![4]]
+        );
+        graph.add_edge(3, 0);
+        graph.add_edge(5, 4);
+        graph.add_edge(5, 0);
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![0, 1, 2, 3], vec![4, 5]]
+        );
+    }
+}
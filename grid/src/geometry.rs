@@ -0,0 +1,235 @@
+/// One of the four directions in a grid. Up is towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// Function that parses a direction given as letter ('U', 'D', 'L', 'R') or as
+    /// arrow ('^', 'v', '<', '>').
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            'R' | '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Function that returns the direction as arrow ('^', 'v', '<', '>').
+    pub fn to_arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    /// Function that returns the change of (x, y) when taking one step.
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    /// Function that returns the direction after turning 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// Function that returns the direction after turning 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        !self.is_horizontal()
+    }
+}
+
+/// A position within a grid. `x` is the column and `y` is the row, both starting at
+/// the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Function that moves the position by the offset. Returns None if the position
+    /// would leave the first quadrant (it does not know the size of a grid).
+    pub fn offset_by(&self, (dx, dy): (isize, isize)) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Function that takes one step into the direction (None if it would leave the
+    /// first quadrant).
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self.offset_by(direction.offset())
+    }
+
+    /// Function that takes the number of steps into the direction (None if it would
+    /// leave the first quadrant).
+    pub fn moved(&self, direction: Direction, distance: usize) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        let distance = isize::try_from(distance).ok()?;
+        self.offset_by((dx * distance, dy * distance))
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// A point on an unbounded plane, e.g. for walking around without a map. Like for a
+/// `Position`, y grows downwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(&self, direction: Direction) -> Self {
+        self.moved(direction, 1)
+    }
+
+    /// Function that takes the number of steps into the direction. Panics on overflow,
+    /// see `checked_moved`.
+    pub fn moved(&self, direction: Direction, distance: isize) -> Self {
+        self.checked_moved(direction, distance)
+            .expect("The point moved outside of the range of isize.")
+    }
+
+    /// Function that takes the number of steps into the direction (None on overflow).
+    pub fn checked_moved(&self, direction: Direction, distance: isize) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        Some(Self {
+            x: self.x.checked_add(dx.checked_mul(distance)?)?,
+            y: self.y.checked_add(dy.checked_mul(distance)?)?,
+        })
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Function that converts the point to a position (None if it is outside of the
+    /// first quadrant).
+    pub fn to_position(&self) -> Option<Position> {
+        Some(Position::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Self::new(position.x as isize, position.y as isize)
+    }
+}
+
+/// The offsets of the eight surrounding cells (including the diagonal ones).
+pub(crate) const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('R'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.reverse());
+            assert_eq!(Direction::from_char(direction.to_arrow()), Some(direction));
+        }
+    }
+
+    #[test]
+    fn test_position() {
+        let position = Position::new(0, 2);
+        assert_eq!(position.step(Direction::Down), Some(Position::new(0, 3)));
+        assert_eq!(position.step(Direction::Left), None);
+        assert_eq!(position.moved(Direction::Up, 2), Some(Position::new(0, 0)));
+        assert_eq!(position.moved(Direction::Up, 3), None);
+        assert_eq!(position.manhattan_distance(&Position::new(3, 0)), 5);
+    }
+
+    #[test]
+    fn test_point() {
+        let point = Point::new(0, 2).moved(Direction::Left, 3);
+        assert_eq!(point, Point::new(-3, 2));
+        assert_eq!(point.step(Direction::Up), Point::new(-3, 1));
+        assert_eq!(point.manhattan_distance(&Point::default()), 5);
+        assert_eq!(point.to_position(), None);
+        assert_eq!(
+            Point::from(Position::new(4, 1)).to_position(),
+            Some(Position::new(4, 1))
+        );
+        assert_eq!(
+            Point::new(isize::MAX, 0).checked_moved(Direction::Right, 1),
+            None
+        );
+    }
+}
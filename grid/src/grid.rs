@@ -0,0 +1,324 @@
+use crate::geometry::{Direction, Position, OFFSETS_8};
+use common::{parse_grid, ParseError};
+use std::{
+    fmt,
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular map of cells, e.g. the character maps of the puzzle inputs. The cells
+/// are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Function that parses a map where every character is one cell (see
+    /// `common::parse_grid`).
+    pub fn from_reader<B, F>(reader: B, expected: &str, parse_cell: F) -> Result<Self, ParseError>
+    where
+        B: BufRead,
+        F: FnMut(char) -> Option<T>,
+    {
+        Ok(Self::from_rows(parse_grid(reader, expected, parse_cell)?))
+    }
+
+    /// Function that creates a grid from its rows. Panics if the rows are not equally
+    /// long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map(Vec::len).unwrap_or(0);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid need to have the same length."
+        );
+        Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.y * self.width + position.x])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.y * self.width + position.x])
+    }
+
+    /// Function that returns the position one step into the direction, or None if it
+    /// is outside of the grid.
+    pub fn neighbor(&self, position: Position, direction: Direction) -> Option<Position> {
+        position
+            .step(direction)
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// Function that returns the position one step into the direction. Leaving the
+    /// grid on one side enters it again on the opposite side.
+    pub fn neighbor_wrapping(&self, position: Position, direction: Direction) -> Position {
+        let (dx, dy) = direction.offset();
+        Position::new(
+            (position.x + self.width).wrapping_add_signed(dx) % self.width,
+            (position.y + self.height).wrapping_add_signed(dy) % self.height,
+        )
+    }
+
+    /// Function that returns the up to four horizontal and vertical neighbors.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbor(position, direction))
+    }
+
+    /// Function that returns the up to eight neighbors including the diagonal ones.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_8.into_iter().filter_map(move |offset| {
+            position
+                .offset_by(offset)
+                .filter(|&neighbor| self.contains(neighbor))
+        })
+    }
+
+    /// Function that returns all positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// Function that returns all cells with their position row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Function that returns the position of the first cell (row by row) that matches.
+    pub fn find<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics for a chunk size of zero, so an empty grid has no rows.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "The column {} is outside of the grid.", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Function that creates a grid of the same size with every cell converted.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, function: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(function).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Function that creates the grid as text with one line per row, using the
+    /// function to show every cell as a character.
+    pub fn render<F: Fn(&T) -> char>(&self, function: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&function).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Function that creates a grid whose cell (x, y) is the cell (x', y') of this grid.
+    fn rearranged<F: Fn(usize, usize) -> (usize, usize)>(
+        &self,
+        width: usize,
+        height: usize,
+        source: F,
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (x, y) = source(x, y);
+                self.cells[y * self.width + x].clone()
+            })
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Function that swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rearranged(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rearranged(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Function that mirrors the grid at a horizontal axis (the first row becomes the
+    /// last one).
+    pub fn mirror_horizontal(&self) -> Self {
+        self.rearranged(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// Function that mirrors the grid at a vertical axis (the first column becomes the
+    /// last one).
+    pub fn mirror_vertical(&self) -> Self {
+        self.rearranged(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(
+            self.contains(position),
+            "The position {:?} is outside of the grid.",
+            position
+        );
+        &self.cells[position.y * self.width + position.x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(
+            self.contains(position),
+            "The position {:?} is outside of the grid.",
+            position
+        );
+        &mut self.cells[position.y * self.width + position.x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Every row is one line, the cells are written next to each other.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row.iter() {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn example() -> Grid<char> {
+        Grid::from_reader(Cursor::new("abc\ndef"), "a letter", |c| {
+            c.is_ascii_lowercase().then_some(c)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 'f');
+        assert_eq!(grid.get(Position::new(3, 1)), None);
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Position::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(
+            Grid::from_reader(Cursor::new("abc\nd1f"), "a letter", |c| {
+                c.is_ascii_lowercase().then_some(c)
+            })
+            .unwrap_err(),
+            ParseError::new(2, 2, "a letter").found("'1'")
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = example();
+        let corner = Position::new(0, 0);
+        assert_eq!(
+            grid.neighbors4(corner).collect::<Vec<Position>>(),
+            vec![Position::new(0, 1), Position::new(1, 0)]
+        );
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+        assert_eq!(grid.neighbors8(Position::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbor(corner, Direction::Up), None);
+        assert_eq!(
+            grid.neighbor_wrapping(corner, Direction::Up),
+            Position::new(0, 1)
+        );
+        assert_eq!(
+            grid.neighbor_wrapping(corner, Direction::Left),
+            Position::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.map(|&c| c == 'a')
+                .render(|&a| if a { '#' } else { '.' }),
+            "#..\n..."
+        );
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = example();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.mirror_horizontal().to_string(), "def\nabc");
+        assert_eq!(grid.mirror_vertical().to_string(), "cba\nfed");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
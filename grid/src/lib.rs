@@ -0,0 +1,5 @@
+pub mod geometry;
+mod grid;
+
+pub use geometry::{Direction, Point, Position};
+pub use grid::Grid;
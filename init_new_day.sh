@@ -8,15 +8,22 @@ fi
 
 project_name=$1
 
-# Create a new Rust project using cargo.
-cargo new --bin "$project_name"
+# Create a new Rust library using cargo.
+# Since we are inside the workspace, cargo also adds it to the workspace members.
+cargo new --lib "$project_name"
 
-# Replace the new main.rs file with the template file.
-rm $project_name/src/main.rs
-cp template/src/main.rs $project_name/src/main.rs
+# Replace the new lib.rs file with the template file and name the solution
+# after the day (day-26 becomes Day26).
+solution_name=$(echo "$project_name" | sed 's/day-/Day/')
+rm $project_name/src/lib.rs
+sed "s/DayXX/$solution_name/g" template/src/lib.rs > $project_name/src/lib.rs
+
+# All days implement the Solution trait of the common crate.
+echo 'common = { path = "../common" }' >> $project_name/Cargo.toml
 
 # Create the template for the input.
 cd $project_name
 touch input
 touch input.test
-echo "\n[profile.release]\ndebug=true" >> Cargo.toml
+
+echo "Do not forget to add $project_name to the dependencies and the dispatch of the aoc runner (aoc/)."
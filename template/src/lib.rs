@@ -0,0 +1,47 @@
+use common::{read_lines, Answer, ParseError, Solution};
+use std::io::BufRead;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<String>;
+    type Parameters = ();
+
+    fn parse<B: BufRead>(reader: B) -> Result<Self::Input, ParseError> {
+        read_lines(reader)
+    }
+
+    /// Function that solves the first task.
+    fn solve_first_task(_input: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        0_usize.into()
+    }
+
+    /// Function that solves the second task.
+    fn solve_second_task(_input: &Self::Input, _parameters: &Self::Parameters) -> Answer {
+        0_usize.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_first_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            DayXX::solve_first_task(&DayXX::parse(reader).unwrap(), &()),
+            Answer::Unsigned(0)
+        );
+    }
+
+    #[test]
+    fn test_second_task() {
+        let reader = BufReader::new(File::open("./input.test").expect("Input file not found."));
+        assert_eq!(
+            DayXX::solve_second_task(&DayXX::parse(reader).unwrap(), &()),
+            Answer::Unsigned(0)
+        );
+    }
+}